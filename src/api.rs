@@ -1,5 +1,17 @@
+
+use std::future::Future;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
 use anyhow::{Context, Result};
-use reqwest::Client;
+use clap::ValueEnum;
+use colored::Colorize;
+use reqwest::{Client, Response, StatusCode};
+use serde::de::DeserializeOwned;
 use serde_json::json;
 
 use crate::models::*;
@@ -7,24 +19,1076 @@ use crate::models::*;
 /// Default Canny API base URL (generic — configure your subdomain via `canny auth`)
 pub const DEFAULT_API_URL: &str = "https://canny.io/api/v1";
 
+/// Default number of retries for transient API failures
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Default HTTP request timeout in seconds
+pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// Shown in place of the API key in errors, logs and dry-run output
+const REDACTED: &str = "****";
+
+/// Default number of items requested per page when fetching every page
+pub const DEFAULT_PAGE_SIZE: u32 = 100;
+
+/// Times a page is refetched when fetching every page fails transiently,
+/// on top of the retries of each request (never more than --max-retries)
+const PAGE_RETRIES: u32 = 2;
+
+/// Default User-Agent header, identifying this CLI and its version
+pub const DEFAULT_USER_AGENT: &str = concat!("canny-cli/", env!("CARGO_PKG_VERSION"));
+
+/// Settings for the underlying HTTP client
+#[derive(Debug, Clone)]
+pub struct HttpOptions {
+    /// Per-request timeout; `None` waits indefinitely
+    pub timeout: Option<Duration>,
+    /// Proxy for all requests; `HTTPS_PROXY`/`HTTP_PROXY` are used otherwise
+    pub proxy: Option<String>,
+    /// PEM file of extra root certificates to trust
+    pub ca_bundle: Option<PathBuf>,
+    /// User-Agent header sent with every request
+    pub user_agent: String,
+    /// Accept invalid TLS certificates (self-signed, expired, wrong host)
+    pub insecure: bool,
+}
+
+impl Default for HttpOptions {
+    fn default() -> Self {
+        Self {
+            timeout: Some(Duration::from_secs(DEFAULT_TIMEOUT_SECS)),
+            proxy: None,
+            ca_bundle: None,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            insecure: false,
+        }
+    }
+}
+
+/// Whether a request only reads data. Reads are safe to retry on any
+/// transient failure; writes are only retried when the request never left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RequestKind {
+    Read,
+    Write,
+    /// A write that leaves the same state however often it's sent, such as
+    /// users/create_or_update
+    Upsert,
+}
+
+impl RequestKind {
+    /// Whether resending after a timeout or error response can't duplicate data
+    fn is_retry_safe(self) -> bool {
+        !matches!(self, RequestKind::Write)
+    }
+}
+
+/// Exponential backoff schedule for retrying transient API failures
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Create a policy with the default backoff (500ms, doubling, capped at 30s)
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+
+    /// Whether a response status indicates a transient failure (429 or 5xx)
+    pub fn is_retryable_status(status: StatusCode) -> bool {
+        status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+    }
+
+    /// Delay before the given retry (0-based), preferring the server's Retry-After
+    pub fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        let delay = retry_after.unwrap_or_else(|| {
+            self.base_delay.saturating_mul(2u32.saturating_pow(attempt))
+        });
+        delay.min(self.max_delay)
+    }
+
+    /// Whether a failed request may succeed if tried again: it timed out or
+    /// never connected. Error responses have already been retried by `send`.
+    pub fn is_transient(error: &anyhow::Error) -> bool {
+        error.chain().any(|cause| {
+            cause
+                .downcast_ref::<reqwest::Error>()
+                .is_some_and(|e| e.is_timeout() || e.is_connect())
+        })
+    }
+}
+
+/// Destination for --audit-log: one JSON record is appended per request
+#[derive(Debug)]
+struct AuditLog {
+    path: PathBuf,
+    file: Mutex<File>,
+    /// Also record reads, not just mutating requests (--audit-all)
+    include_reads: bool,
+    /// Set once a write has failed, so the warning is only printed once
+    write_failed: AtomicBool,
+}
+
+/// Counters for every request a client sends, reported by --stats
+#[derive(Debug, Default)]
+struct RequestStats {
+    requests: AtomicU64,
+    retries: AtomicU64,
+    rate_limited: AtomicU64,
+    client_errors: AtomicU64,
+    server_errors: AtomicU64,
+    response_bytes: AtomicU64,
+    latency_micros: AtomicU64,
+}
+
+impl RequestStats {
+    /// Count a response's status class, size, and time since it was sent
+    fn record_response(&self, status: StatusCode, bytes: usize, latency: Duration) {
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            self.rate_limited.fetch_add(1, Ordering::Relaxed);
+        }
+        if status.is_client_error() {
+            self.client_errors.fetch_add(1, Ordering::Relaxed);
+        } else if status.is_server_error() {
+            self.server_errors.fetch_add(1, Ordering::Relaxed);
+        }
+        self.response_bytes.fetch_add(bytes as u64, Ordering::Relaxed);
+        self.record_latency(latency);
+    }
+
+    fn record_latency(&self, latency: Duration) {
+        self.latency_micros
+            .fetch_add(latency.as_micros() as u64, Ordering::Relaxed);
+    }
+}
+
+/// Process exit codes, so scripts can tell failure kinds apart
+pub const EXIT_FAILURE: i32 = 1;
+pub const EXIT_AUTH: i32 = 2;
+pub const EXIT_NOT_FOUND: i32 = 3;
+pub const EXIT_RATE_LIMITED: i32 = 4;
+pub const EXIT_VALIDATION: i32 = 5;
+pub const EXIT_INCOMPLETE: i32 = 6;
+
+/// An error response returned by the Canny API
+#[derive(Debug)]
+pub enum CannyApiError {
+    /// 401/403: the API key is missing, invalid, or lacks access
+    Unauthorized { path: String, message: String },
+    /// 404: the requested resource does not exist
+    NotFound { path: String, message: String },
+    /// 429: too many requests
+    RateLimited {
+        path: String,
+        retry_after: Option<Duration>,
+    },
+    /// Other 4xx: the request was rejected, usually due to invalid parameters
+    Validation {
+        path: String,
+        status: StatusCode,
+        message: String,
+    },
+    /// 5xx: Canny failed to handle the request
+    Server {
+        path: String,
+        status: StatusCode,
+        body: String,
+    },
+}
+
+impl CannyApiError {
+    /// Classify an error response. Canny reports errors as `{"error": "..."}`;
+    /// bodies that don't match fall back to the raw text.
+    fn from_response(
+        status: StatusCode,
+        path: &str,
+        retry_after: Option<Duration>,
+        body: String,
+    ) -> Self {
+        let path = path.to_string();
+        let message = serde_json::from_str::<serde_json::Value>(&body)
+            .ok()
+            .and_then(|v| v.get("error").and_then(|e| e.as_str()).map(String::from))
+            .unwrap_or_else(|| body.clone());
+
+        match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                CannyApiError::Unauthorized { path, message }
+            }
+            StatusCode::NOT_FOUND => CannyApiError::NotFound { path, message },
+            StatusCode::TOO_MANY_REQUESTS => CannyApiError::RateLimited { path, retry_after },
+            s if s.is_server_error() => CannyApiError::Server {
+                path,
+                status: s,
+                body,
+            },
+            s => CannyApiError::Validation {
+                path,
+                status: s,
+                message,
+            },
+        }
+    }
+}
+
+impl CannyApiError {
+    /// The process exit code for this kind of failure
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            CannyApiError::Unauthorized { .. } => EXIT_AUTH,
+            CannyApiError::NotFound { .. } => EXIT_NOT_FOUND,
+            CannyApiError::RateLimited { .. } => EXIT_RATE_LIMITED,
+            CannyApiError::Validation { .. } => EXIT_VALIDATION,
+            CannyApiError::Server { .. } => EXIT_FAILURE,
+        }
+    }
+}
+
+impl std::fmt::Display for CannyApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CannyApiError::Unauthorized { path, message } => write!(
+                f,
+                "Unauthorized ({}): {}. Check your API key or run `canny auth`",
+                path, message
+            ),
+            CannyApiError::NotFound { path, message } => {
+                write!(f, "Not found ({}): {}", path, message)
+            }
+            CannyApiError::RateLimited { path, retry_after } => match retry_after {
+                Some(d) => write!(
+                    f,
+                    "Rate limited ({}): retry after {}s",
+                    path,
+                    d.as_secs()
+                ),
+                None => write!(f, "Rate limited ({}): try again later", path),
+            },
+            CannyApiError::Validation {
+                path,
+                status,
+                message,
+            } => write!(f, "API error ({}) on {}: {}", status, path, message),
+            CannyApiError::Server { path, status, body } => {
+                write!(f, "Server error ({}) on {}: {}", status, path, body)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CannyApiError {}
+
+/// ID handed back for objects a `--dry-run` write would have created
+pub const DRY_RUN_ID: &str = "dry-run";
+
+/// Returned instead of a parsed response when `--raw` has printed the body
+#[derive(Debug)]
+pub struct RawPrinted;
+
+impl std::fmt::Display for RawPrinted {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "raw response printed")
+    }
+}
+
+impl std::error::Error for RawPrinted {}
+
+/// Returned by a read still in flight when the --deadline passes
+#[derive(Debug)]
+pub struct DeadlineExceeded;
+
+impl std::fmt::Display for DeadlineExceeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "--deadline reached before the request finished")
+    }
+}
+
+impl std::error::Error for DeadlineExceeded {}
+
+/// Whether a listing's page failed only because the --deadline cut it off
+fn is_deadline_exceeded(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| cause.is::<DeadlineExceeded>())
+}
+
+/// Parse a Retry-After header given in seconds
+fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// Canny API version an endpoint is served under
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ApiVersion {
+    V1,
+    V2,
+}
+
+impl ApiVersion {
+    /// Largest `limit` list endpoints under this version accept
+    fn max_page_size(self) -> u32 {
+        match self {
+            ApiVersion::V1 => 10000,
+            ApiVersion::V2 => 100,
+        }
+    }
+}
+
+impl std::fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiVersion::V1 => write!(f, "v1"),
+            ApiVersion::V2 => write!(f, "v2"),
+        }
+    }
+}
+
+/// Strip a trailing version segment (`/v1`, `/v2`) from a configured API URL,
+/// leaving the base that versioned endpoints are composed onto. URLs without
+/// one (e.g. a proxy) are used as the base unchanged.
+fn base_url(api_url: &str) -> String {
+    let trimmed = api_url.trim_end_matches('/');
+    match trimmed.rsplit_once('/') {
+        Some((base, "v1" | "v2")) => base.to_string(),
+        _ => trimmed.to_string(),
+    }
+}
+
 /// Canny API client
 pub struct CannyClient {
     client: Client,
-    api_url: String,
+    base_url: String,
     api_key: String,
+    api_version: Option<ApiVersion>,
+    timeout: Option<Duration>,
+    max_retries: u32,
+    page_size: u32,
+    verbose: bool,
+    dry_run: bool,
+    raw: bool,
+    /// Lookups in flight that --raw leaves alone
+    raw_paused: AtomicUsize,
+    deadline: Option<Instant>,
+    deadline_warned: AtomicBool,
+    skip_invalid: bool,
+    skipped: AtomicUsize,
+    /// A listing stopped early on a failed page
+    incomplete: AtomicBool,
+    stats: RequestStats,
+    started: Instant,
+    audit_log: Option<AuditLog>,
 }
 
 impl CannyClient {
-    /// Create a new Canny API client
-    pub fn new(api_url: String, api_key: String) -> Self {
-        Self {
-            client: Client::new(),
-            api_url,
+    /// Create a new Canny API client with custom HTTP settings
+    pub fn with_http_options(
+        api_url: String,
+        api_key: String,
+        options: &HttpOptions,
+    ) -> Result<Self> {
+        // Responses are gzip-compressed when Canny supports it; idle
+        // connections are kept so paginated and concurrent calls reuse them.
+        let mut builder = Client::builder()
+            .gzip(true)
+            .user_agent(&options.user_agent)
+            .pool_idle_timeout(Duration::from_secs(90))
+            .tcp_keepalive(Duration::from_secs(60));
+        if let Some(timeout) = options.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(ref proxy) = options.proxy {
+            let proxy = reqwest::Proxy::all(proxy)
+                .with_context(|| format!("Invalid proxy URL: {}", proxy))?;
+            builder = builder.proxy(proxy);
+        }
+        if let Some(ref path) = options.ca_bundle {
+            let pem = std::fs::read(path)
+                .with_context(|| format!("Failed to read CA bundle {}", path.display()))?;
+            let certs = reqwest::Certificate::from_pem_bundle(&pem)
+                .with_context(|| format!("Invalid PEM in CA bundle {}", path.display()))?;
+            if certs.is_empty() {
+                anyhow::bail!("No certificates found in CA bundle {}", path.display());
+            }
+            for cert in certs {
+                builder = builder.add_root_certificate(cert);
+            }
+        }
+        if options.insecure {
+            eprintln!(
+                "{} --insecure: TLS certificates are not verified, so responses could come \
+                 from anyone. Use only against test servers.",
+                "warning:".yellow()
+            );
+            builder = builder.danger_accept_invalid_certs(true);
+        }
+        let client = builder.build().context("Failed to build HTTP client")?;
+
+        Ok(Self {
+            client,
+            base_url: base_url(&api_url),
             api_key,
+            api_version: None,
+            timeout: options.timeout,
+            max_retries: DEFAULT_MAX_RETRIES,
+            page_size: DEFAULT_PAGE_SIZE,
+            verbose: false,
+            dry_run: false,
+            raw: false,
+            raw_paused: AtomicUsize::new(0),
+            deadline: None,
+            deadline_warned: AtomicBool::new(false),
+            skip_invalid: false,
+            skipped: AtomicUsize::new(0),
+            incomplete: AtomicBool::new(false),
+            stats: RequestStats::default(),
+            started: Instant::now(),
+            audit_log: None,
+        })
+    }
+
+    /// Force every request to use one API version instead of each
+    /// endpoint's own
+    pub fn with_api_version(mut self, api_version: Option<ApiVersion>) -> Self {
+        self.api_version = api_version;
+        self
+    }
+
+    /// Compose the URL for an endpoint path under the given API version
+    fn endpoint(&self, version: ApiVersion, path: &str) -> String {
+        let version = self.api_version.unwrap_or(version);
+        format!("{}/{}/{}", self.base_url, version, path)
+    }
+
+    /// Set how many items to request per page when fetching every page
+    pub fn with_page_size(mut self, page_size: u32) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Per-request page size for an endpoint under the given API version,
+    /// clamped to what that version accepts
+    pub fn page_size(&self, version: ApiVersion) -> u32 {
+        let version = self.api_version.unwrap_or(version);
+        self.page_size.min(version.max_page_size())
+    }
+
+    /// Set how many times transient failures (429, 5xx) are retried
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Log diagnostics such as retry attempts to stderr
+    pub fn with_verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+
+    /// Print write requests instead of sending them. Each skipped request
+    /// succeeds with an empty response, creates with [`DRY_RUN_ID`] as the
+    /// new ID, so commands that make several writes print all of them.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Print the body of the first successful read response as-is (pretty
+    /// printed if it is JSON) instead of parsing it. The request then fails
+    /// with [`RawPrinted`] so callers stop before printing typed output.
+    /// Reads made through [`Self::lookup`] are parsed as usual.
+    pub fn with_raw(mut self, raw: bool) -> Self {
+        self.raw = raw;
+        self
+    }
+
+    /// Run a request that only resolves a command's arguments, such as
+    /// finding a user by email, so --raw skips it and prints the command's
+    /// own request instead
+    pub async fn lookup<T>(&self, request: impl Future<Output = T>) -> T {
+        self.raw_paused.fetch_add(1, Ordering::Relaxed);
+        let result = request.await;
+        self.raw_paused.fetch_sub(1, Ordering::Relaxed);
+        result
+    }
+
+    /// Stop following pages once `deadline` has elapsed from now, keeping
+    /// what was fetched so far. A read still in flight then is cut off with
+    /// [`DeadlineExceeded`]; writes always run to the end.
+    pub fn with_deadline(mut self, deadline: Option<Duration>) -> Self {
+        self.deadline = deadline.map(|d| Instant::now() + d);
+        self
+    }
+
+    /// Whether the --deadline has passed
+    pub fn deadline_passed(&self) -> bool {
+        self.deadline.is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Whether the --deadline has passed, warning the first time that
+    /// results stop after `fetched` items. Results cut short this way are
+    /// incomplete; see [`Self::results_incomplete`].
+    fn deadline_reached(&self, fetched: usize) -> bool {
+        let reached = self.deadline_passed();
+        if reached {
+            self.incomplete.store(true, Ordering::Relaxed);
+        }
+        if reached && !self.deadline_warned.swap(true, Ordering::Relaxed) {
+            eprintln!(
+                "{} --deadline reached after {} items; results are incomplete",
+                "warning:".yellow(),
+                fetched
+            );
+        }
+        reached
+    }
+
+    /// Fetch one page of an every-page listing, refetching it up to
+    /// `PAGE_RETRIES` times if it still fails transiently after the
+    /// per-request retries
+    async fn fetch_page_with_retry<T, F, Fut>(&self, mut fetch_page: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let policy = RetryPolicy::new(PAGE_RETRIES.min(self.max_retries));
+        let mut attempt = 0;
+        loop {
+            match fetch_page().await {
+                Err(e) if attempt < policy.max_retries && RetryPolicy::is_transient(&e) => {
+                    let delay = policy.delay(attempt, None);
+                    eprintln!(
+                        "{} page request failed, refetching ({}/{}) in {:.1}s: {:#}",
+                        "warning:".yellow(),
+                        attempt + 1,
+                        policy.max_retries,
+                        delay.as_secs_f64(),
+                        e
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
         }
     }
 
-    /// List posts from a board
+    /// Warn that a page failed for good after `fetched` items, which are
+    /// returned as incomplete results instead of failing the whole listing;
+    /// see [`Self::results_incomplete`]. Errors before any items were
+    /// fetched are passed on.
+    fn partial_results(&self, fetched: usize, error: anyhow::Error) -> Result<()> {
+        if fetched == 0 {
+            return Err(error);
+        }
+        self.incomplete.store(true, Ordering::Relaxed);
+        eprintln!(
+            "{} stopped fetching pages after {} items: {:#}; results are incomplete",
+            "warning:".yellow(),
+            fetched,
+            error
+        );
+        Ok(())
+    }
+
+    /// Hand each page of a cursor-paginated endpoint to `on_page` as it's
+    /// fetched, starting at `start` (None for the first page). `fetch_page`
+    /// is given the cursor from the previous page and returns the page's
+    /// items, whether more pages exist, and the next cursor. Returns the
+    /// cursor to resume from later: the last one the API handed back, or
+    /// `start`. A page that keeps failing ends the listing early with a
+    /// warning, keeping the items handed over before it.
+    pub async fn for_each_cursor_page<T, F, Fut, P>(
+        &self,
+        start: Option<String>,
+        mut fetch_page: F,
+        mut on_page: P,
+    ) -> Result<Option<String>>
+    where
+        F: FnMut(Option<String>) -> Fut,
+        Fut: Future<Output = Result<(Vec<T>, bool, Option<String>)>>,
+        P: FnMut(Vec<T>) -> Result<()>,
+    {
+        let mut fetched = 0;
+        let mut cursor = start;
+
+        loop {
+            let page = self.fetch_page_with_retry(|| fetch_page(cursor.clone())).await;
+            let (items, has_more, next_cursor) = match page {
+                Ok(page) => page,
+                Err(e) if is_deadline_exceeded(&e) => {
+                    self.deadline_reached(fetched);
+                    break;
+                }
+                Err(e) => {
+                    self.partial_results(fetched, e)?;
+                    break;
+                }
+            };
+
+            if items.is_empty() {
+                break;
+            }
+
+            fetched += items.len();
+            on_page(items)?;
+
+            // Stop if the API has nothing further, or hands back the same cursor
+            let done = !has_more || next_cursor.is_none() || next_cursor == cursor;
+            if next_cursor.is_some() {
+                cursor = next_cursor;
+            }
+            if done {
+                break;
+            }
+
+            // Safety limit to prevent infinite loops
+            if fetched > 100000 || self.deadline_reached(fetched) {
+                break;
+            }
+        }
+
+        Ok(cursor)
+    }
+
+    /// Hand each page of a skip-paginated endpoint to `on_page` as it's
+    /// fetched. `fetch_page` is given the offset to fetch from and returns
+    /// the page's items, whether more pages exist, and the page's raw item
+    /// count; the offset advances by that count, so items dropped by
+    /// --skip-invalid aren't fetched again. Returns the number of items
+    /// handed over.
+    pub async fn for_each_skip_page<T, F, Fut, P>(
+        &self,
+        mut fetch_page: F,
+        mut on_page: P,
+    ) -> Result<usize>
+    where
+        F: FnMut(u32) -> Fut,
+        Fut: Future<Output = Result<(Vec<T>, bool, usize)>>,
+        P: AsyncFnMut(Vec<T>) -> Result<()>,
+    {
+        let mut fetched = 0;
+        let mut skip = 0;
+
+        loop {
+            let (items, has_more, raw_count) = match fetch_page(skip as u32).await {
+                Ok(page) => page,
+                Err(e) if is_deadline_exceeded(&e) => {
+                    self.deadline_reached(fetched);
+                    break;
+                }
+                Err(e) => return Err(e),
+            };
+
+            // A page can be empty after --skip-invalid and still be followed
+            // by more; only an empty response ends the listing
+            if raw_count == 0 {
+                break;
+            }
+
+            fetched += items.len();
+            skip += raw_count;
+            on_page(items).await?;
+
+            if !has_more {
+                break;
+            }
+
+            // Safety limit to prevent infinite loops
+            if fetched > 100000 || self.deadline_reached(fetched) {
+                break;
+            }
+        }
+
+        Ok(fetched)
+    }
+
+    /// Drop list items that fail to parse, with a warning, instead of
+    /// failing the whole response
+    pub fn with_skip_invalid(mut self, skip_invalid: bool) -> Self {
+        self.skip_invalid = skip_invalid;
+        self
+    }
+
+    /// Append a JSON record of every mutating request (and every read too
+    /// when `include_reads` is set) to the file at `path`
+    pub fn with_audit_log(mut self, path: Option<&Path>, include_reads: bool) -> Result<Self> {
+        self.audit_log = path
+            .map(|path| {
+                let file = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .with_context(|| format!("Failed to open audit log {}", path.display()))?;
+                Ok::<_, anyhow::Error>(AuditLog {
+                    path: path.to_path_buf(),
+                    file: Mutex::new(file),
+                    include_reads,
+                    write_failed: AtomicBool::new(false),
+                })
+            })
+            .transpose()?;
+        Ok(self)
+    }
+
+    /// Print request counters since the client was created to stderr, in
+    /// Prometheus text format
+    pub fn print_stats(&self) {
+        let count = |counter: &AtomicU64| counter.load(Ordering::Relaxed);
+        let stats = &self.stats;
+        eprintln!("# canny request stats");
+        eprintln!("canny_requests_total {}", count(&stats.requests));
+        eprintln!("canny_retries_total {}", count(&stats.retries));
+        eprintln!("canny_responses_429_total {}", count(&stats.rate_limited));
+        eprintln!("canny_responses_4xx_total {}", count(&stats.client_errors));
+        eprintln!("canny_responses_5xx_total {}", count(&stats.server_errors));
+        eprintln!("canny_response_bytes_total {}", count(&stats.response_bytes));
+        eprintln!(
+            "canny_request_duration_seconds_total {:.3}",
+            count(&stats.latency_micros) as f64 / 1_000_000.0
+        );
+        eprintln!("canny_elapsed_seconds {:.3}", self.started.elapsed().as_secs_f64());
+    }
+
+    /// How many list items --skip-invalid has dropped so far
+    pub fn skipped_count(&self) -> usize {
+        self.skipped.load(Ordering::Relaxed)
+    }
+
+    /// Whether a listing kept the items it had after a page failed for good
+    /// or the --deadline passed, so what was printed is only part of the
+    /// results
+    pub fn results_incomplete(&self) -> bool {
+        self.incomplete.load(Ordering::Relaxed)
+    }
+
+    /// With --skip-invalid, remove the items of a JSON array that don't
+    /// parse as `T`, warning about each; otherwise leave it untouched so
+    /// parsing fails as usual
+    fn drop_invalid<T: DeserializeOwned>(&self, items: &mut serde_json::Value, what: &str) {
+        if !self.skip_invalid {
+            return;
+        }
+        let Some(items) = items.as_array_mut() else {
+            return;
+        };
+        items.retain(|item| match serde_json::from_value::<T>(item.clone()) {
+            Ok(_) => true,
+            Err(e) => {
+                let id = item.get("id").and_then(|id| id.as_str()).unwrap_or("(no id)");
+                eprintln!("{} skipped invalid {} item {}: {}", "warning:".yellow(), what, id, e);
+                self.skipped.fetch_add(1, Ordering::Relaxed);
+                false
+            }
+        });
+    }
+
+    /// Parse a list response whose items of type `T` are under `key`,
+    /// dropping invalid items under --skip-invalid. Also returns how many
+    /// items the response held before any were dropped, which is what a
+    /// skip offset has to advance by.
+    fn parse_list<R, T>(&self, text: &str, key: &str) -> Result<(R, usize)>
+    where
+        R: DeserializeOwned,
+        T: DeserializeOwned,
+    {
+        let mut value: serde_json::Value =
+            serde_json::from_str(text).context("Failed to parse response")?;
+        let mut raw_count = 0;
+        if let Some(items) = value.get_mut(key) {
+            raw_count = items.as_array().map_or(0, Vec::len);
+            self.drop_invalid::<T>(items, key);
+        }
+        let response = serde_json::from_value(value).context("Failed to parse response")?;
+        Ok((response, raw_count))
+    }
+
+    /// Whether write requests are being printed instead of sent
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// POST a request body and return the response text, retrying transient
+    /// failures with exponential backoff. Reads are cut off at the
+    /// --deadline; writes run to the end so it's always known whether they
+    /// went through.
+    async fn send(
+        &self,
+        url: String,
+        body: &serde_json::Value,
+        kind: RequestKind,
+    ) -> Result<String> {
+        match self.deadline {
+            Some(deadline) if kind == RequestKind::Read => {
+                tokio::time::timeout_at(deadline.into(), self.send_with_retries(url, body, kind))
+                    .await
+                    .map_err(|_| DeadlineExceeded)?
+            }
+            _ => self.send_with_retries(url, body, kind).await,
+        }
+    }
+
+    /// [`Self::send`] without the deadline
+    async fn send_with_retries(
+        &self,
+        url: String,
+        body: &serde_json::Value,
+        kind: RequestKind,
+    ) -> Result<String> {
+        if self.dry_run && kind != RequestKind::Read {
+            println!("[dry-run] POST {}", url);
+            println!("{}", self.redacted_body(body, true));
+            return Ok(json!({ "id": DRY_RUN_ID }).to_string());
+        }
+
+        let policy = RetryPolicy::new(self.max_retries);
+        let mut attempt = 0;
+
+        loop {
+            if self.verbose {
+                eprintln!("> POST {}", url);
+                eprintln!("> {}", self.redacted_body(body, false));
+            }
+
+            let request = self.client.post(&url).json(body);
+
+            self.stats.requests.fetch_add(1, Ordering::Relaxed);
+            let sent = Instant::now();
+            let response = match request.send().await {
+                Ok(response) => response,
+                Err(e) => {
+                    self.stats.record_latency(sent.elapsed());
+                    self.audit(&url, body, kind, None, &e.to_string());
+                    // A connection error means nothing reached the server, so
+                    // even non-idempotent writes can be safely resent.
+                    let retryable = e.is_connect() || (kind.is_retry_safe() && e.is_timeout());
+                    if retryable && attempt < policy.max_retries {
+                        let delay = policy.delay(attempt, None);
+                        self.record_retry(&url, attempt, delay, &e.to_string());
+                        tokio::time::sleep(delay).await;
+                        attempt += 1;
+                        continue;
+                    }
+                    if e.is_timeout() {
+                        let secs = self.timeout.map(|t| t.as_secs()).unwrap_or_default();
+                        return Err(anyhow::Error::new(e).context(format!(
+                            "Request to {} timed out after {}s (use --timeout to allow longer, or 0 to disable)",
+                            url, secs
+                        )));
+                    }
+                    return Err(anyhow::Error::new(e).context("Failed to send request"));
+                }
+            };
+
+            let status = response.status();
+            let retry_after = retry_after(&response);
+            let path = response.url().path().to_string();
+            // Redact once here so error messages, logs and --raw output built
+            // from the response can never echo the key back
+            let text = self.redact(&response.text().await?);
+            self.stats.record_response(status, text.len(), sent.elapsed());
+            self.audit(&url, body, kind, Some(status), &text);
+
+            if self.verbose {
+                eprintln!("< {}", status);
+                eprintln!("< {}", text);
+            }
+
+            if kind.is_retry_safe()
+                && RetryPolicy::is_retryable_status(status)
+                && attempt < policy.max_retries
+            {
+                let delay = policy.delay(attempt, retry_after);
+                self.record_retry(&url, attempt, delay, &status.to_string());
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+                continue;
+            }
+
+            if !status.is_success() {
+                return Err(CannyApiError::from_response(status, &path, retry_after, text).into());
+            }
+
+            if self.raw
+                && kind == RequestKind::Read
+                && self.raw_paused.load(Ordering::Relaxed) == 0
+            {
+                match serde_json::from_str::<serde_json::Value>(&text) {
+                    Ok(value) => println!("{}", serde_json::to_string_pretty(&value)?),
+                    Err(_) => println!("{}", text),
+                }
+                return Err(RawPrinted.into());
+            }
+
+            return Ok(text);
+        }
+    }
+
+    /// Serialize a request body for logs with the API key removed
+    fn redacted_body(&self, body: &serde_json::Value, pretty: bool) -> String {
+        let mut shown = body.clone();
+        if shown.get("apiKey").is_some() {
+            shown["apiKey"] = json!(REDACTED);
+        }
+        let text = if pretty {
+            serde_json::to_string_pretty(&shown)
+        } else {
+            serde_json::to_string(&shown)
+        };
+        self.redact(&text.unwrap_or_default())
+    }
+
+    /// Replace any occurrence of the API key in text shown to the user
+    fn redact(&self, text: &str) -> String {
+        if self.api_key.is_empty() {
+            text.to_string()
+        } else {
+            text.replace(&self.api_key, REDACTED)
+        }
+    }
+
+    /// Append a record of one request to the audit log, if there is one.
+    /// Without a status the request never got a response, and `response` is
+    /// the error instead. The request has already been sent by then, so a
+    /// failed write only warns rather than failing a write that went through.
+    fn audit(
+        &self,
+        url: &str,
+        body: &serde_json::Value,
+        kind: RequestKind,
+        status: Option<StatusCode>,
+        response: &str,
+    ) {
+        let Some(ref log) = self.audit_log else {
+            return;
+        };
+        if kind == RequestKind::Read && !log.include_reads {
+            return;
+        }
+
+        let path = reqwest::Url::parse(url)
+            .map(|u| u.path().to_string())
+            .unwrap_or_else(|_| url.to_string());
+        let request_body: serde_json::Value =
+            serde_json::from_str(&self.redacted_body(body, false)).unwrap_or_default();
+        let response_id = status
+            .and_then(|_| serde_json::from_str::<serde_json::Value>(response).ok())
+            .and_then(|value| value.get("id").and_then(|id| id.as_str()).map(String::from));
+        let record = json!({
+            "timestamp": chrono::Utc::now().to_rfc3339(),
+            "method": "POST",
+            "path": path,
+            "body": request_body,
+            "status": status.map(|s| s.as_u16()),
+            "id": response_id,
+            "error": status.is_none().then_some(response),
+        });
+
+        let mut file = log.file.lock().unwrap_or_else(|e| e.into_inner());
+        if let Err(e) = writeln!(file, "{}", record) {
+            if !log.write_failed.swap(true, Ordering::Relaxed) {
+                eprintln!(
+                    "{} failed to write audit log {}: {}",
+                    "warning:".yellow(),
+                    log.path.display(),
+                    e
+                );
+            }
+        }
+    }
+
+    /// Count a retry, logging it with --verbose
+    fn record_retry(&self, url: &str, attempt: u32, delay: Duration, reason: &str) {
+        self.stats.retries.fetch_add(1, Ordering::Relaxed);
+        if self.verbose {
+            eprintln!(
+                "Retrying {} ({}/{}) in {:.1}s: {}",
+                url,
+                attempt + 1,
+                self.max_retries,
+                delay.as_secs_f64(),
+                reason
+            );
+        }
+    }
+
+    /// POST a JSON object to any endpoint path with the API key added and
+    /// return the response text. Paths starting with `v1/` or `v2/` choose
+    /// that version; others use v1 (or --api-version).
+    pub async fn request(&self, path: &str, mut body: serde_json::Value) -> Result<String> {
+        let Some(object) = body.as_object_mut() else {
+            anyhow::bail!("Request body must be a JSON object");
+        };
+        object.insert("apiKey".to_string(), json!(self.api_key));
+
+        let path = path.trim_start_matches('/');
+        let url = match path.split_once('/') {
+            Some(("v1" | "v2", _)) => format!("{}/{}", self.base_url, path),
+            _ => self.endpoint(ApiVersion::V1, path),
+        };
+
+        // The endpoint may write, so it's treated like any other write
+        self.send(url, &body, RequestKind::Write).await
+    }
+
+    /// Create an object from a request body given whole (`--input-json`),
+    /// with the API key added, and return the new ID. Only
+    /// `users/create_or_update`, an upsert, is resent after a timeout.
+    pub async fn create_from_body(
+        &self,
+        path: &str,
+        mut body: serde_json::Value,
+    ) -> Result<String> {
+        let Some(object) = body.as_object_mut() else {
+            anyhow::bail!("Request body must be a JSON object");
+        };
+        object.insert("apiKey".to_string(), json!(self.api_key));
+
+        let kind = if path == "users/create_or_update" {
+            RequestKind::Upsert
+        } else {
+            RequestKind::Write
+        };
+        let text = self.send(self.endpoint(ApiVersion::V1, path), &body, kind).await?;
+
+        let result: CreateResponse =
+            serde_json::from_str(&text).context("Failed to parse response")?;
+        Ok(result.id)
+    }
+
+    /// List every user segment
+    pub async fn list_segments(&self) -> Result<Vec<CannySegment>> {
+        let body = json!({
+            "apiKey": self.api_key,
+        });
+
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "segments/list"), &body, RequestKind::Read)
+            .await?;
+
+        let value: serde_json::Value =
+            serde_json::from_str(&text).context("Failed to parse response as JSON")?;
+        let mut segments = value.get("segments").cloned().unwrap_or(json!([]));
+        self.drop_invalid::<CannySegment>(&mut segments, "segments");
+
+        serde_json::from_value(segments).context("Failed to parse segments from response")
+    }
+
+    /// List posts from a board, with the raw item count from
+    /// `parse_list`
+    #[allow(clippy::too_many_arguments)]
     pub async fn list_posts(
         &self,
         board_id: &str,
@@ -36,7 +1100,7 @@ impl CannyClient {
         search: Option<&str>,
         company_id: Option<&str>,
         tag_ids: Option<Vec<&str>>,
-    ) -> Result<PostsListResponse> {
+    ) -> Result<(PostsListResponse, usize)> {
         let mut body = json!({
             "apiKey": self.api_key,
             "boardID": board_id,
@@ -67,22 +1131,94 @@ impl CannyClient {
             body["tagIDs"] = json!(tags);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/posts/list", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "posts/list"), &body, RequestKind::Read)
+            .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
+        self.parse_list::<_, CannyPost>(&text, "posts")
+    }
 
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+    /// List every post on a board by following `hasMore` until exhausted
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_all_posts<F>(
+        &self,
+        board_id: &str,
+        sort: Option<&str>,
+        status: Option<&str>,
+        author_id: Option<&str>,
+        search: Option<&str>,
+        company_id: Option<&str>,
+        tag_ids: Option<Vec<&str>>,
+        mut on_progress: Option<F>,
+    ) -> Result<Vec<CannyPost>>
+    where
+        F: FnMut(usize),
+    {
+        let mut all_posts: Vec<CannyPost> = Vec::new();
+
+        self.for_each_post_page(
+            board_id,
+            sort,
+            status,
+            author_id,
+            search,
+            company_id,
+            tag_ids,
+            async |posts| {
+                all_posts.extend(posts);
+                if let Some(ref mut progress) = on_progress {
+                    progress(all_posts.len());
+                }
+                Ok(())
+            },
+        )
+        .await?;
+
+        Ok(all_posts)
+    }
 
-        serde_json::from_str(&text).context("Failed to parse response")
+    /// Hand each page of a board's posts to `on_page` as it's fetched, so
+    /// callers can process every post without holding them all in memory.
+    /// Returns the total number of posts fetched.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn for_each_post_page<F>(
+        &self,
+        board_id: &str,
+        sort: Option<&str>,
+        status: Option<&str>,
+        author_id: Option<&str>,
+        search: Option<&str>,
+        company_id: Option<&str>,
+        tag_ids: Option<Vec<&str>>,
+        mut on_page: F,
+    ) -> Result<usize>
+    where
+        F: AsyncFnMut(Vec<CannyPost>) -> Result<()>,
+    {
+        let limit = self.page_size(ApiVersion::V1);
+        self.for_each_skip_page(
+            |skip| {
+                let tag_ids = tag_ids.clone();
+                async move {
+                    let (response, raw_count) = self
+                        .list_posts(
+                            board_id,
+                            Some(limit),
+                            Some(skip),
+                            sort,
+                            status,
+                            author_id,
+                            search,
+                            company_id,
+                            tag_ids,
+                        )
+                        .await?;
+                    Ok((response.posts, response.has_more, raw_count))
+                }
+            },
+            async |posts| on_page(posts).await,
+        )
+        .await
     }
 
     /// Retrieve a single post by ID, URL name (with board ID), or both
@@ -106,20 +1242,9 @@ impl CannyClient {
             body["boardID"] = json!(b);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/posts/retrieve", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "posts/retrieve"), &body, RequestKind::Read)
+            .await?;
 
         let result: PostRetrieveResponse =
             serde_json::from_str(&text).context("Failed to parse response")?;
@@ -127,6 +1252,7 @@ impl CannyClient {
     }
 
     /// Create a new post
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_post(
         &self,
         board_id: &str,
@@ -142,6 +1268,51 @@ impl CannyClient {
         image_urls: Option<Vec<&str>>,
         created_at: Option<&str>,
     ) -> Result<String> {
+        let body = self.create_post_body(
+            board_id,
+            author_id,
+            title,
+            details,
+            category_id,
+            by_id,
+            custom_fields,
+            eta,
+            eta_public,
+            owner_id,
+            image_urls,
+            created_at,
+        );
+
+        let text = self
+            .send(
+                self.endpoint(ApiVersion::V1, "posts/create"),
+                &body,
+                RequestKind::Write,
+            )
+            .await?;
+
+        let result: CreateResponse =
+            serde_json::from_str(&text).context("Failed to parse response")?;
+        Ok(result.id)
+    }
+
+    /// Request body for [`Self::create_post`]
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_post_body(
+        &self,
+        board_id: &str,
+        author_id: &str,
+        title: &str,
+        details: Option<&str>,
+        category_id: Option<&str>,
+        by_id: Option<&str>,
+        custom_fields: Option<serde_json::Value>,
+        eta: Option<&str>,
+        eta_public: Option<bool>,
+        owner_id: Option<&str>,
+        image_urls: Option<Vec<&str>>,
+        created_at: Option<&str>,
+    ) -> serde_json::Value {
         let mut body = json!({
             "apiKey": self.api_key,
             "boardID": board_id,
@@ -177,24 +1348,7 @@ impl CannyClient {
             body["createdAt"] = json!(ca);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/posts/create", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
-
-        let result: CreateResponse =
-            serde_json::from_str(&text).context("Failed to parse response")?;
-        Ok(result.id)
+        body
     }
 
     /// Change the status of a post
@@ -207,6 +1361,31 @@ impl CannyClient {
         comment: Option<&str>,
         comment_image_urls: Option<Vec<&str>>,
     ) -> Result<()> {
+        let body = self.change_post_status_body(
+            post_id,
+            changer_id,
+            status,
+            notify_voters,
+            comment,
+            comment_image_urls,
+        );
+
+        self.send(self.endpoint(ApiVersion::V1, "posts/change_status"), &body, RequestKind::Write)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Request body for [`Self::change_post_status`]
+    pub fn change_post_status_body(
+        &self,
+        post_id: &str,
+        changer_id: &str,
+        status: &str,
+        notify_voters: bool,
+        comment: Option<&str>,
+        comment_image_urls: Option<Vec<&str>>,
+    ) -> serde_json::Value {
         let mut body = json!({
             "apiKey": self.api_key,
             "postID": post_id,
@@ -222,25 +1401,11 @@ impl CannyClient {
             body["commentImageURLs"] = json!(urls);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/posts/change_status", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status_code = response.status();
-        let text = response.text().await?;
-
-        if !status_code.is_success() {
-            anyhow::bail!("API error ({}): {}", status_code, text);
-        }
-
-        Ok(())
+        body
     }
 
     /// Update a post
+    #[allow(clippy::too_many_arguments)]
     pub async fn update_post(
         &self,
         post_id: &str,
@@ -250,7 +1415,38 @@ impl CannyClient {
         eta: Option<&str>,
         eta_public: Option<bool>,
         custom_fields: Option<serde_json::Value>,
+        owner_id: Option<&str>,
     ) -> Result<()> {
+        let body = self.update_post_body(
+            post_id,
+            title,
+            details,
+            image_urls,
+            eta,
+            eta_public,
+            custom_fields,
+            owner_id,
+        );
+
+        self.send(self.endpoint(ApiVersion::V1, "posts/update"), &body, RequestKind::Write)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Request body for [`Self::update_post`]
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_post_body(
+        &self,
+        post_id: &str,
+        title: Option<&str>,
+        details: Option<&str>,
+        image_urls: Option<Vec<&str>>,
+        eta: Option<&str>,
+        eta_public: Option<bool>,
+        custom_fields: Option<serde_json::Value>,
+        owner_id: Option<&str>,
+    ) -> serde_json::Value {
         let mut body = json!({
             "apiKey": self.api_key,
             "postID": post_id,
@@ -274,181 +1470,148 @@ impl CannyClient {
         if let Some(cf) = custom_fields {
             body["customFields"] = cf;
         }
+        if let Some(o) = owner_id {
+            body["ownerID"] = json!(o);
+        }
 
-        let response = self
-            .client
-            .post(format!("{}/posts/update", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
+        body
+    }
 
-        let status = response.status();
-        let text = response.text().await?;
+    /// Delete a post
+    pub async fn delete_post(&self, post_id: &str) -> Result<()> {
+        let body = self.delete_post_body(post_id);
 
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        self.send(self.endpoint(ApiVersion::V1, "posts/delete"), &body, RequestKind::Write)
+            .await?;
 
         Ok(())
     }
 
-    /// Delete a post
-    pub async fn delete_post(&self, post_id: &str) -> Result<()> {
-        let body = json!({
+    /// Request body for [`Self::delete_post`]
+    pub fn delete_post_body(&self, post_id: &str) -> serde_json::Value {
+        json!({
             "apiKey": self.api_key,
             "postID": post_id,
-        });
-
-        let response = self
-            .client
-            .post(format!("{}/posts/delete", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
+        })
+    }
 
-        let status = response.status();
-        let text = response.text().await?;
+    /// Merge a post into another; `post_id` is folded into `merge_into_id`,
+    /// which keeps the combined votes and comments
+    pub async fn merge_posts(&self, post_id: &str, merge_into_id: &str) -> Result<()> {
+        let body = self.merge_posts_body(post_id, merge_into_id);
 
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        self.send(self.endpoint(ApiVersion::V1, "posts/merge"), &body, RequestKind::Write)
+            .await?;
 
         Ok(())
     }
 
+    /// Request body for [`Self::merge_posts`]
+    pub fn merge_posts_body(&self, post_id: &str, merge_into_id: &str) -> serde_json::Value {
+        json!({
+            "apiKey": self.api_key,
+            "postID": post_id,
+            "mergeID": merge_into_id,
+        })
+    }
+
     /// Change the category of a post
     pub async fn change_post_category(&self, post_id: &str, category_id: &str) -> Result<()> {
-        let body = json!({
+        let body = self.change_post_category_body(post_id, category_id);
+
+        self.send(self.endpoint(ApiVersion::V1, "posts/change_category"), &body, RequestKind::Write)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Request body for [`Self::change_post_category`]
+    pub fn change_post_category_body(&self, post_id: &str, category_id: &str) -> serde_json::Value {
+        json!({
             "apiKey": self.api_key,
             "postID": post_id,
             "categoryID": category_id,
-        });
-
-        let response = self
-            .client
-            .post(format!("{}/posts/change_category", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
+        })
+    }
 
-        let status = response.status();
-        let text = response.text().await?;
+    /// Add a tag to a post
+    pub async fn add_post_tag(&self, post_id: &str, tag_id: &str) -> Result<()> {
+        let body = self.add_post_tag_body(post_id, tag_id);
 
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        self.send(self.endpoint(ApiVersion::V1, "posts/add_tag"), &body, RequestKind::Write)
+            .await?;
 
         Ok(())
     }
 
-    /// Add a tag to a post
-    pub async fn add_post_tag(&self, post_id: &str, tag_id: &str) -> Result<()> {
-        let body = json!({
+    /// Request body for [`Self::add_post_tag`]
+    pub fn add_post_tag_body(&self, post_id: &str, tag_id: &str) -> serde_json::Value {
+        json!({
             "apiKey": self.api_key,
             "postID": post_id,
             "tagID": tag_id,
-        });
+        })
+    }
 
-        let response = self
-            .client
-            .post(format!("{}/posts/add_tag", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
+    /// Remove a tag from a post
+    pub async fn remove_post_tag(&self, post_id: &str, tag_id: &str) -> Result<()> {
+        let body = self.remove_post_tag_body(post_id, tag_id);
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        self.send(self.endpoint(ApiVersion::V1, "posts/remove_tag"), &body, RequestKind::Write)
+            .await?;
 
         Ok(())
     }
 
-    /// Remove a tag from a post
-    pub async fn remove_post_tag(&self, post_id: &str, tag_id: &str) -> Result<()> {
-        let body = json!({
+    /// Request body for [`Self::remove_post_tag`]
+    pub fn remove_post_tag_body(&self, post_id: &str, tag_id: &str) -> serde_json::Value {
+        json!({
             "apiKey": self.api_key,
             "postID": post_id,
             "tagID": tag_id,
-        });
-
-        let response = self
-            .client
-            .post(format!("{}/posts/remove_tag", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
+        })
+    }
 
-        let status = response.status();
-        let text = response.text().await?;
+    /// Link a Jira issue to a post
+    pub async fn link_post_jira(&self, post_id: &str, issue_key: &str) -> Result<()> {
+        let body = self.link_post_jira_body(post_id, issue_key);
 
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        self.send(self.endpoint(ApiVersion::V1, "posts/link_jira"), &body, RequestKind::Write)
+            .await?;
 
         Ok(())
     }
 
-    /// Link a Jira issue to a post
-    pub async fn link_post_jira(&self, post_id: &str, issue_key: &str) -> Result<()> {
-        let body = json!({
+    /// Request body for [`Self::link_post_jira`]
+    pub fn link_post_jira_body(&self, post_id: &str, issue_key: &str) -> serde_json::Value {
+        json!({
             "apiKey": self.api_key,
             "postID": post_id,
             "issueKey": issue_key,
-        });
-
-        let response = self
-            .client
-            .post(format!("{}/posts/link_jira", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
+        })
+    }
 
-        let status = response.status();
-        let text = response.text().await?;
+    /// Unlink a Jira issue from a post
+    pub async fn unlink_post_jira(&self, post_id: &str, issue_key: &str) -> Result<()> {
+        let body = self.unlink_post_jira_body(post_id, issue_key);
 
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        self.send(self.endpoint(ApiVersion::V1, "posts/unlink_jira"), &body, RequestKind::Write)
+            .await?;
 
         Ok(())
     }
 
-    /// Unlink a Jira issue from a post
-    pub async fn unlink_post_jira(&self, post_id: &str, issue_key: &str) -> Result<()> {
-        let body = json!({
+    /// Request body for [`Self::unlink_post_jira`]
+    pub fn unlink_post_jira_body(&self, post_id: &str, issue_key: &str) -> serde_json::Value {
+        json!({
             "apiKey": self.api_key,
             "postID": post_id,
             "issueKey": issue_key,
-        });
-
-        let response = self
-            .client
-            .post(format!("{}/posts/unlink_jira", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
-
-        Ok(())
+        })
     }
 
-    /// List comments for a post
+    /// List comments for a post, with the raw item count from
+    /// `parse_list`
     pub async fn list_comments(
         &self,
         post_id: Option<&str>,
@@ -457,7 +1620,7 @@ impl CannyClient {
         company_id: Option<&str>,
         limit: Option<u32>,
         skip: Option<u32>,
-    ) -> Result<CommentsListResponse> {
+    ) -> Result<(CommentsListResponse, usize)> {
         let mut body = json!({
             "apiKey": self.api_key,
         });
@@ -481,25 +1644,15 @@ impl CannyClient {
             body["skip"] = json!(s);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/comments/list", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "comments/list"), &body, RequestKind::Read)
+            .await?;
 
-        serde_json::from_str(&text).context("Failed to parse response")
+        self.parse_list::<_, CannyComment>(&text, "comments")
     }
 
     /// Create a comment on a post
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_comment(
         &self,
         post_id: &str,
@@ -511,6 +1664,43 @@ impl CannyClient {
         internal: Option<bool>,
         should_notify_voters: Option<bool>,
     ) -> Result<String> {
+        let body = self.create_comment_body(
+            post_id,
+            author_id,
+            value,
+            parent_id,
+            created_at,
+            image_urls,
+            internal,
+            should_notify_voters,
+        );
+
+        let text = self
+            .send(
+                self.endpoint(ApiVersion::V1, "comments/create"),
+                &body,
+                RequestKind::Write,
+            )
+            .await?;
+
+        let result: CreateResponse =
+            serde_json::from_str(&text).context("Failed to parse response")?;
+        Ok(result.id)
+    }
+
+    /// Request body for [`Self::create_comment`]
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_comment_body(
+        &self,
+        post_id: &str,
+        author_id: &str,
+        value: &str,
+        parent_id: Option<&str>,
+        created_at: Option<&str>,
+        image_urls: Option<Vec<&str>>,
+        internal: Option<bool>,
+        should_notify_voters: Option<bool>,
+    ) -> serde_json::Value {
         let mut body = json!({
             "apiKey": self.api_key,
             "postID": post_id,
@@ -534,24 +1724,28 @@ impl CannyClient {
             body["shouldNotifyVoters"] = json!(n);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/comments/create", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        body
+    }
 
-        let result: CreateResponse =
-            serde_json::from_str(&text).context("Failed to parse response")?;
-        Ok(result.id)
+    /// List every comment on a post by following `hasMore` until exhausted
+    pub async fn list_all_comments(&self, post_id: &str) -> Result<Vec<CannyComment>> {
+        let mut all_comments = Vec::new();
+        let limit = self.page_size(ApiVersion::V1);
+        self.for_each_skip_page(
+            async |skip| {
+                let (response, raw_count) = self
+                    .list_comments(Some(post_id), None, None, None, Some(limit), Some(skip))
+                    .await?;
+                Ok((response.comments, response.has_more, raw_count))
+            },
+            async |items| {
+                all_comments.extend(items);
+                Ok(())
+            },
+        )
+        .await?;
+
+        Ok(all_comments)
     }
 
     /// Retrieve a single comment by ID
@@ -561,51 +1755,68 @@ impl CannyClient {
             "id": comment_id,
         });
 
-        let response = self
-            .client
-            .post(format!("{}/comments/retrieve", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "comments/retrieve"), &body, RequestKind::Read)
+            .await?;
 
         let result: CommentRetrieveResponse =
             serde_json::from_str(&text).context("Failed to parse response")?;
         Ok(result.comment)
     }
 
-    /// Delete a comment by ID
-    pub async fn delete_comment(&self, comment_id: &str) -> Result<()> {
-        let body = json!({
+    /// Update a comment's text and images
+    pub async fn update_comment(
+        &self,
+        comment_id: &str,
+        value: &str,
+        image_urls: Option<Vec<&str>>,
+    ) -> Result<()> {
+        let body = self.update_comment_body(comment_id, value, image_urls);
+
+        self.send(self.endpoint(ApiVersion::V1, "comments/update"), &body, RequestKind::Write)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Request body for [`Self::update_comment`]
+    pub fn update_comment_body(
+        &self,
+        comment_id: &str,
+        value: &str,
+        image_urls: Option<Vec<&str>>,
+    ) -> serde_json::Value {
+        let mut body = json!({
             "apiKey": self.api_key,
             "commentID": comment_id,
+            "value": value,
         });
 
-        let response = self
-            .client
-            .post(format!("{}/comments/delete", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
+        if let Some(urls) = image_urls {
+            body["imageURLs"] = json!(urls);
+        }
 
-        let status = response.status();
-        let text = response.text().await?;
+        body
+    }
+
+    /// Delete a comment by ID
+    pub async fn delete_comment(&self, comment_id: &str) -> Result<()> {
+        let body = self.delete_comment_body(comment_id);
 
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        self.send(self.endpoint(ApiVersion::V1, "comments/delete"), &body, RequestKind::Write)
+            .await?;
 
         Ok(())
     }
 
+    /// Request body for [`Self::delete_comment`]
+    pub fn delete_comment_body(&self, comment_id: &str) -> serde_json::Value {
+        json!({
+            "apiKey": self.api_key,
+            "commentID": comment_id,
+        })
+    }
+
     /// List categories for a board
     pub async fn list_categories(
         &self,
@@ -625,22 +1836,11 @@ impl CannyClient {
             body["skip"] = json!(s);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/categories/list", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "categories/list"), &body, RequestKind::Read)
+            .await?;
 
-        serde_json::from_str(&text).context("Failed to parse response")
+        self.parse_list::<_, CannyCategory>(&text, "categories").map(|(response, _)| response)
     }
 
     /// Retrieve a single category by ID
@@ -650,20 +1850,9 @@ impl CannyClient {
             "id": category_id,
         });
 
-        let response = self
-            .client
-            .post(format!("{}/categories/retrieve", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "categories/retrieve"), &body, RequestKind::Read)
+            .await?;
 
         let result: CategoryRetrieveResponse =
             serde_json::from_str(&text).context("Failed to parse response")?;
@@ -678,6 +1867,25 @@ impl CannyClient {
         parent_id: Option<&str>,
         subscribe_admins: bool,
     ) -> Result<String> {
+        let body = self.create_category_body(board_id, name, parent_id, subscribe_admins);
+
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "categories/create"), &body, RequestKind::Write)
+            .await?;
+
+        let result: CreateResponse =
+            serde_json::from_str(&text).context("Failed to parse response")?;
+        Ok(result.id)
+    }
+
+    /// Request body for [`Self::create_category`]
+    pub fn create_category_body(
+        &self,
+        board_id: &str,
+        name: &str,
+        parent_id: Option<&str>,
+        subscribe_admins: bool,
+    ) -> serde_json::Value {
         let mut body = json!({
             "apiKey": self.api_key,
             "boardID": board_id,
@@ -689,89 +1897,145 @@ impl CannyClient {
             body["parentID"] = json!(p);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/categories/create", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
+        body
+    }
 
-        let status = response.status();
-        let text = response.text().await?;
+    /// Rename a category or move it under a different parent, keeping its posts
+    pub async fn update_category(
+        &self,
+        category_id: &str,
+        name: Option<&str>,
+        parent_id: Option<&str>,
+    ) -> Result<()> {
+        let body = self.update_category_body(category_id, name, parent_id);
 
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        self.send(self.endpoint(ApiVersion::V1, "categories/update"), &body, RequestKind::Write)
+            .await?;
 
-        let result: CreateResponse =
-            serde_json::from_str(&text).context("Failed to parse response")?;
-        Ok(result.id)
+        Ok(())
     }
 
-    /// Delete a category
-    pub async fn delete_category(&self, category_id: &str) -> Result<()> {
-        let body = json!({
+    /// Request body for [`Self::update_category`]
+    pub fn update_category_body(
+        &self,
+        category_id: &str,
+        name: Option<&str>,
+        parent_id: Option<&str>,
+    ) -> serde_json::Value {
+        let mut body = json!({
             "apiKey": self.api_key,
             "categoryID": category_id,
         });
 
-        let response = self
-            .client
-            .post(format!("{}/categories/delete", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
+        if let Some(n) = name {
+            body["name"] = json!(n);
+        }
+        if let Some(p) = parent_id {
+            body["parentID"] = json!(p);
+        }
+
+        body
+    }
 
-        let status = response.status();
-        let text = response.text().await?;
+    /// Delete a category
+    pub async fn delete_category(&self, category_id: &str) -> Result<()> {
+        let body = self.delete_category_body(category_id);
 
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        self.send(self.endpoint(ApiVersion::V1, "categories/delete"), &body, RequestKind::Write)
+            .await?;
 
         Ok(())
     }
 
+    /// Request body for [`Self::delete_category`]
+    pub fn delete_category_body(&self, category_id: &str) -> serde_json::Value {
+        json!({
+            "apiKey": self.api_key,
+            "categoryID": category_id,
+        })
+    }
+
     /// List all users (automatically depaginates using cursor pagination)
     /// Note: Uses API v2 which requires cursor-based pagination
     /// If `on_progress` is provided, it will be called with the current count after each page
-    pub async fn list_users<F>(&self, mut on_progress: Option<F>) -> Result<Vec<CannyUserFull>>
+    pub async fn list_users<F>(
+        &self,
+        company_id: Option<&str>,
+        search: Option<&str>,
+        max_users: Option<usize>,
+        mut on_progress: Option<F>,
+    ) -> Result<Vec<CannyUserFull>>
     where
         F: FnMut(usize),
     {
         let mut all_users: Vec<CannyUserFull> = Vec::new();
-        let mut cursor: Option<String> = None;
-        let limit: u32 = 100; // API max is 100
-
-        loop {
-            let (users, next_cursor, has_next) =
-                self.fetch_users_page(cursor.as_deref(), limit).await?;
-
-            if users.is_empty() {
-                break;
-            }
 
+        self.for_each_user_page(company_id, search, max_users, |users| {
             all_users.extend(users);
-
             if let Some(ref mut progress) = on_progress {
                 progress(all_users.len());
             }
+            Ok(())
+        })
+        .await?;
+
+        Ok(all_users)
+    }
 
-            if !has_next || next_cursor.is_none() {
+    /// Hand each page of users to `on_page` as it's fetched, stopping once
+    /// `max_users` have been handed over. Returns the total number of users.
+    /// A page that keeps failing ends the listing early with a warning.
+    pub async fn for_each_user_page<F>(
+        &self,
+        company_id: Option<&str>,
+        search: Option<&str>,
+        max_users: Option<usize>,
+        mut on_page: F,
+    ) -> Result<usize>
+    where
+        F: FnMut(Vec<CannyUserFull>) -> Result<()>,
+    {
+        let mut fetched = 0;
+        let mut cursor: Option<String> = None;
+        let max_users = max_users.unwrap_or(100000);
+
+        while fetched < max_users {
+            // Don't ask for more than the cap still needs
+            let page_size = self.page_size(ApiVersion::V2) as usize;
+            let limit = (max_users - fetched).min(page_size) as u32;
+            let page = self
+                .fetch_page_with_retry(|| {
+                    self.fetch_users_page(cursor.as_deref(), limit, company_id, search)
+                })
+                .await;
+            let (mut users, next_cursor, has_next) = match page {
+                Ok(page) => page,
+                Err(e) if is_deadline_exceeded(&e) => {
+                    self.deadline_reached(fetched);
+                    break;
+                }
+                Err(e) => {
+                    self.partial_results(fetched, e)?;
+                    break;
+                }
+            };
+
+            if users.is_empty() {
                 break;
             }
 
-            cursor = next_cursor;
+            users.truncate(max_users - fetched);
+            fetched += users.len();
+            on_page(users)?;
 
-            // Safety limit to prevent infinite loops
-            if all_users.len() > 100000 {
+            if !has_next || next_cursor.is_none() || self.deadline_reached(fetched) {
                 break;
             }
+
+            cursor = next_cursor;
         }
 
-        Ok(all_users)
+        Ok(fetched)
     }
 
     /// Fetch a single page of users using cursor pagination
@@ -780,6 +2044,8 @@ impl CannyClient {
         &self,
         cursor: Option<&str>,
         limit: u32,
+        company_id: Option<&str>,
+        search: Option<&str>,
     ) -> Result<(Vec<CannyUserFull>, Option<String>, bool)> {
         let mut body = json!({
             "apiKey": self.api_key,
@@ -789,23 +2055,17 @@ impl CannyClient {
         if let Some(c) = cursor {
             body["cursor"] = json!(c);
         }
+        if let Some(c) = company_id {
+            body["companyID"] = json!(c);
+        }
+        if let Some(s) = search {
+            body["search"] = json!(s);
+        }
 
         // Users endpoint uses v2 API
-        let base_url = self.api_url.replace("/v1", "/v2");
-        let response = self
-            .client
-            .post(format!("{}/users/list", base_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        let text = self
+            .send(self.endpoint(ApiVersion::V2, "users/list"), &body, RequestKind::Read)
+            .await?;
 
         let value: serde_json::Value =
             serde_json::from_str(&text).context("Failed to parse response as JSON")?;
@@ -815,11 +2075,12 @@ impl CannyClient {
             .ok_or_else(|| anyhow::anyhow!("Expected object response"))?;
 
         // v2 API returns "items" array
-        let users_value = obj
+        let mut users_value = obj
             .get("items")
             .or_else(|| obj.get("users"))
             .cloned()
             .unwrap_or(json!([]));
+        self.drop_invalid::<CannyUserFull>(&mut users_value, "users");
 
         let users: Vec<CannyUserFull> =
             serde_json::from_value(users_value).context("Failed to parse users from response")?;
@@ -854,20 +2115,9 @@ impl CannyClient {
             body["email"] = json!(e);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/users/retrieve", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "users/retrieve"), &body, RequestKind::Read)
+            .await?;
 
         // The API returns the user object directly, or an error
         let result: Option<CannyUserFull> = serde_json::from_str(&text).ok();
@@ -875,6 +2125,7 @@ impl CannyClient {
     }
 
     /// Create or update a user
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_or_update_user(
         &self,
         user_id: &str,
@@ -886,6 +2137,43 @@ impl CannyClient {
         company_id: Option<&str>,
         custom_fields: Option<serde_json::Value>,
     ) -> Result<String> {
+        let body = self.create_or_update_user_body(
+            user_id,
+            email,
+            id,
+            name,
+            avatar_url,
+            created,
+            company_id,
+            custom_fields,
+        );
+
+        let text = self
+            .send(
+                self.endpoint(ApiVersion::V1, "users/create_or_update"),
+                &body,
+                RequestKind::Upsert,
+            )
+            .await?;
+
+        let result: CreateResponse =
+            serde_json::from_str(&text).context("Failed to parse response")?;
+        Ok(result.id)
+    }
+
+    /// Request body for [`Self::create_or_update_user`]
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_or_update_user_body(
+        &self,
+        user_id: &str,
+        email: &str,
+        id: Option<&str>,
+        name: Option<&str>,
+        avatar_url: Option<&str>,
+        created: Option<&str>,
+        company_id: Option<&str>,
+        custom_fields: Option<serde_json::Value>,
+    ) -> serde_json::Value {
         let mut body = json!({
             "apiKey": self.api_key,
             "userID": user_id,
@@ -911,51 +2199,27 @@ impl CannyClient {
             body["customFields"] = cf;
         }
 
-        let response = self
-            .client
-            .post(format!("{}/users/create_or_update", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
-
-        let result: CreateResponse =
-            serde_json::from_str(&text).context("Failed to parse response")?;
-        Ok(result.id)
+        body
     }
 
     /// Delete a user by ID
     pub async fn delete_user(&self, user_id: &str) -> Result<()> {
-        let body = json!({
-            "apiKey": self.api_key,
-            "userID": user_id,
-        });
-
-        let response = self
-            .client
-            .post(format!("{}/users/delete", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
+        let body = self.delete_user_body(user_id);
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        self.send(self.endpoint(ApiVersion::V1, "users/delete"), &body, RequestKind::Write)
+            .await?;
 
         Ok(())
     }
 
+    /// Request body for [`Self::delete_user`]
+    pub fn delete_user_body(&self, user_id: &str) -> serde_json::Value {
+        json!({
+            "apiKey": self.api_key,
+            "userID": user_id,
+        })
+    }
+
     /// Find a user by ID, email, or name
     pub async fn find_user(
         &self,
@@ -977,20 +2241,9 @@ impl CannyClient {
             body["name"] = json!(n);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/users/find", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "users/find"), &body, RequestKind::Read)
+            .await?;
 
         let result: UserFindResponse =
             serde_json::from_str(&text).context("Failed to parse response")?;
@@ -999,28 +2252,106 @@ impl CannyClient {
 
     /// Remove a user from a company
     pub async fn remove_user_from_company(&self, user_id: &str, company_id: &str) -> Result<()> {
-        let body = json!({
+        let body = self.remove_user_from_company_body(user_id, company_id);
+
+        self.send(
+            self.endpoint(ApiVersion::V1, "users/remove_from_company"),
+            &body,
+            RequestKind::Write,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Request body for [`Self::remove_user_from_company`]
+    pub fn remove_user_from_company_body(
+        &self,
+        user_id: &str,
+        company_id: &str,
+    ) -> serde_json::Value {
+        json!({
             "apiKey": self.api_key,
             "userID": user_id,
             "companyID": company_id,
+        })
+    }
+
+    /// Add a user to a company, creating the company if it doesn't exist
+    /// yet. Canny has no endpoint for either: companies are attached
+    /// through the `companies` list of users/create_or_update, which also
+    /// needs the user's current email and name, so the user is looked up
+    /// first. `company` is built with [`Self::company_record`].
+    pub async fn add_user_to_company(
+        &self,
+        user_id: &str,
+        company: serde_json::Value,
+    ) -> Result<()> {
+        let user = self
+            .find_user(Some(user_id), None, None)
+            .await?
+            .with_context(|| format!("No user found with ID {}", user_id))?;
+        let body = self.add_user_to_company_body(&user, company);
+
+        self.send(
+            self.endpoint(ApiVersion::V1, "users/create_or_update"),
+            &body,
+            RequestKind::Upsert,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Request body for [`Self::add_user_to_company`]
+    pub fn add_user_to_company_body(
+        &self,
+        user: &CannyUserFull,
+        company: serde_json::Value,
+    ) -> serde_json::Value {
+        let mut body = json!({
+            "apiKey": self.api_key,
+            "companies": [company],
         });
 
-        let response = self
-            .client
-            .post(format!("{}/users/remove_from_company", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
+        if let Some(ref u) = user.user_id {
+            body["userID"] = json!(u);
+        }
+        if let Some(ref e) = user.email {
+            body["email"] = json!(e);
+        }
+        if let Some(ref n) = user.name {
+            body["name"] = json!(n);
+        }
+
+        body
+    }
 
-        let status = response.status();
-        let text = response.text().await?;
+    /// A company as listed in a user's `companies`, identified by your own
+    /// company ID
+    pub fn company_record(
+        company_id: &str,
+        name: Option<&str>,
+        monthly_spend: Option<f64>,
+        custom_fields: Option<serde_json::Value>,
+        created: Option<&str>,
+    ) -> serde_json::Value {
+        let mut company = json!({ "id": company_id });
 
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
+        if let Some(n) = name {
+            company["name"] = json!(n);
+        }
+        if let Some(ms) = monthly_spend {
+            company["monthlySpend"] = json!(ms);
+        }
+        if let Some(cf) = custom_fields {
+            company["customFields"] = cf;
+        }
+        if let Some(c) = created {
+            company["created"] = json!(c);
         }
 
-        Ok(())
+        company
     }
 
     /// List all boards
@@ -1029,25 +2360,15 @@ impl CannyClient {
             "apiKey": self.api_key,
         });
 
-        let response = self
-            .client
-            .post(format!("{}/boards/list", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "boards/list"), &body, RequestKind::Read)
+            .await?;
 
         let value: serde_json::Value =
             serde_json::from_str(&text).context("Failed to parse response as JSON")?;
 
-        let boards_value = value.get("boards").cloned().unwrap_or(json!([]));
+        let mut boards_value = value.get("boards").cloned().unwrap_or(json!([]));
+        self.drop_invalid::<CannyBoard>(&mut boards_value, "boards");
 
         let boards: Vec<CannyBoard> =
             serde_json::from_value(boards_value).context("Failed to parse boards from response")?;
@@ -1062,20 +2383,9 @@ impl CannyClient {
             "id": board_id,
         });
 
-        let response = self
-            .client
-            .post(format!("{}/boards/retrieve", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "boards/retrieve"), &body, RequestKind::Read)
+            .await?;
 
         let result: BoardRetrieveResponse =
             serde_json::from_str(&text).context("Failed to parse response")?;
@@ -1084,63 +2394,93 @@ impl CannyClient {
 
     /// Create a new board
     pub async fn create_board(&self, name: &str) -> Result<String> {
-        let body = json!({
-            "apiKey": self.api_key,
-            "name": name,
-        });
-
-        let response = self
-            .client
-            .post(format!("{}/boards/create", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
+        let body = self.create_board_body(name);
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "boards/create"), &body, RequestKind::Write)
+            .await?;
 
         let result: CreateResponse =
             serde_json::from_str(&text).context("Failed to parse response")?;
         Ok(result.id)
     }
 
-    /// Delete a board by ID
-    pub async fn delete_board(&self, board_id: &str) -> Result<()> {
-        let body = json!({
+    /// Request body for [`Self::create_board`]
+    pub fn create_board_body(&self, name: &str) -> serde_json::Value {
+        json!({
             "apiKey": self.api_key,
-            "id": board_id,
-        });
+            "name": name,
+        })
+    }
 
-        let response = self
-            .client
-            .post(format!("{}/boards/delete", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
+    /// Update a board's name or privacy settings
+    pub async fn update_board(
+        &self,
+        board_id: &str,
+        name: Option<&str>,
+        is_private: Option<bool>,
+        private_comments: Option<bool>,
+    ) -> Result<()> {
+        let body = self.update_board_body(board_id, name, is_private, private_comments);
+
+        self.send(self.endpoint(ApiVersion::V1, "boards/update"), &body, RequestKind::Write)
+            .await?;
+
+        Ok(())
+    }
 
-        let status = response.status();
-        let text = response.text().await?;
+    /// Request body for [`Self::update_board`]
+    pub fn update_board_body(
+        &self,
+        board_id: &str,
+        name: Option<&str>,
+        is_private: Option<bool>,
+        private_comments: Option<bool>,
+    ) -> serde_json::Value {
+        let mut body = json!({
+            "apiKey": self.api_key,
+            "boardID": board_id,
+        });
 
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
+        if let Some(n) = name {
+            body["name"] = json!(n);
+        }
+        if let Some(p) = is_private {
+            body["isPrivate"] = json!(p);
         }
+        if let Some(pc) = private_comments {
+            body["privateComments"] = json!(pc);
+        }
+
+        body
+    }
+
+    /// Delete a board by ID
+    pub async fn delete_board(&self, board_id: &str) -> Result<()> {
+        let body = self.delete_board_body(board_id);
+
+        self.send(self.endpoint(ApiVersion::V1, "boards/delete"), &body, RequestKind::Write)
+            .await?;
 
         Ok(())
     }
 
-    /// List tags for a board
+    /// Request body for [`Self::delete_board`]
+    pub fn delete_board_body(&self, board_id: &str) -> serde_json::Value {
+        json!({
+            "apiKey": self.api_key,
+            "id": board_id,
+        })
+    }
+
+    /// List tags for a board, with the raw item count from
+    /// `parse_list`
     pub async fn list_tags(
         &self,
         board_id: &str,
         limit: Option<u32>,
         skip: Option<u32>,
-    ) -> Result<TagsListResponse> {
+    ) -> Result<(TagsListResponse, usize)> {
         let mut body = json!({
             "apiKey": self.api_key,
             "boardID": board_id,
@@ -1153,22 +2493,42 @@ impl CannyClient {
             body["skip"] = json!(s);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/tags/list", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "tags/list"), &body, RequestKind::Read)
+            .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
+        self.parse_list::<_, CannyTag>(&text, "tags")
+    }
 
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+    /// List every tag on a board by following `hasMore` until exhausted
+    pub async fn list_all_tags(&self, board_id: &str) -> Result<Vec<CannyTag>> {
+        let mut all_tags = Vec::new();
+        self.for_each_tag_page(board_id, |tags| {
+            all_tags.extend(tags);
+            Ok(())
+        })
+        .await?;
+
+        Ok(all_tags)
+    }
+
+    /// Hand each page of a board's tags to `on_page` as it's fetched
+    pub async fn for_each_tag_page<F>(&self, board_id: &str, mut on_page: F) -> Result<()>
+    where
+        F: FnMut(Vec<CannyTag>) -> Result<()>,
+    {
+        let limit = self.page_size(ApiVersion::V1);
+        self.for_each_skip_page(
+            async |skip| {
+                let (response, raw_count) =
+                    self.list_tags(board_id, Some(limit), Some(skip)).await?;
+                Ok((response.tags, response.has_more, raw_count))
+            },
+            async |items| on_page(items),
+        )
+        .await?;
 
-        serde_json::from_str(&text).context("Failed to parse response")
+        Ok(())
     }
 
     /// Retrieve a single tag by ID
@@ -1178,77 +2538,134 @@ impl CannyClient {
             "id": tag_id,
         });
 
-        let response = self
-            .client
-            .post(format!("{}/tags/retrieve", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "tags/retrieve"), &body, RequestKind::Read)
+            .await?;
+
+        let result: TagRetrieveResponse =
+            serde_json::from_str(&text).context("Failed to parse response")?;
+        Ok(result.tag)
+    }
+
+    /// Create a new tag
+    pub async fn create_tag(&self, board_id: &str, name: &str) -> Result<String> {
+        let body = self.create_tag_body(board_id, name);
+
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "tags/create"), &body, RequestKind::Write)
+            .await?;
+
+        let result: CreateResponse =
+            serde_json::from_str(&text).context("Failed to parse response")?;
+        Ok(result.id)
+    }
+
+    /// Request body for [`Self::create_tag`]
+    pub fn create_tag_body(&self, board_id: &str, name: &str) -> serde_json::Value {
+        json!({
+            "apiKey": self.api_key,
+            "boardID": board_id,
+            "name": name,
+        })
+    }
 
-        let status = response.status();
-        let text = response.text().await?;
+    /// Rename a tag
+    pub async fn update_tag(&self, tag_id: &str, name: &str, board_id: Option<&str>) -> Result<()> {
+        let body = self.update_tag_body(tag_id, name, board_id);
 
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        self.send(self.endpoint(ApiVersion::V1, "tags/update"), &body, RequestKind::Write)
+            .await?;
 
-        let result: TagRetrieveResponse =
-            serde_json::from_str(&text).context("Failed to parse response")?;
-        Ok(result.tag)
+        Ok(())
     }
 
-    /// Create a new tag
-    pub async fn create_tag(&self, board_id: &str, name: &str) -> Result<String> {
-        let body = json!({
+    /// Request body for [`Self::update_tag`]
+    pub fn update_tag_body(
+        &self,
+        tag_id: &str,
+        name: &str,
+        board_id: Option<&str>,
+    ) -> serde_json::Value {
+        let mut body = json!({
             "apiKey": self.api_key,
-            "boardID": board_id,
+            "tagID": tag_id,
             "name": name,
         });
 
-        let response = self
-            .client
-            .post(format!("{}/tags/create", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
+        if let Some(b) = board_id {
+            body["boardID"] = json!(b);
         }
 
-        let result: CreateResponse =
-            serde_json::from_str(&text).context("Failed to parse response")?;
-        Ok(result.id)
+        body
     }
 
     /// Delete a tag
     pub async fn delete_tag(&self, tag_id: &str) -> Result<()> {
-        let body = json!({
+        let body = self.delete_tag_body(tag_id);
+
+        self.send(self.endpoint(ApiVersion::V1, "tags/delete"), &body, RequestKind::Write)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Request body for [`Self::delete_tag`]
+    pub fn delete_tag_body(&self, tag_id: &str) -> serde_json::Value {
+        json!({
             "apiKey": self.api_key,
             "tagID": tag_id,
-        });
+        })
+    }
 
-        let response = self
-            .client
-            .post(format!("{}/tags/delete", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
+    /// List every company by following the cursor until `hasNextPage` is false
+    pub async fn list_all_companies<F>(
+        &self,
+        search: Option<&str>,
+        segment: Option<&str>,
+        mut on_progress: Option<F>,
+    ) -> Result<Vec<CannyCompany>>
+    where
+        F: FnMut(usize),
+    {
+        let mut all_companies: Vec<CannyCompany> = Vec::new();
 
-        let status = response.status();
-        let text = response.text().await?;
+        self.for_each_company_page(search, segment, None, |companies| {
+            all_companies.extend(companies);
+            if let Some(ref mut progress) = on_progress {
+                progress(all_companies.len());
+            }
+            Ok(())
+        })
+        .await?;
 
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        Ok(all_companies)
+    }
 
-        Ok(())
+    /// Hand each page of companies to `on_page` as it's fetched, starting at
+    /// `cursor` (None for the first page). Returns the cursor to resume from
+    /// later; see [`Self::for_each_cursor_page`].
+    pub async fn for_each_company_page<F>(
+        &self,
+        search: Option<&str>,
+        segment: Option<&str>,
+        cursor: Option<String>,
+        on_page: F,
+    ) -> Result<Option<String>>
+    where
+        F: FnMut(Vec<CannyCompany>) -> Result<()>,
+    {
+        let limit = self.page_size(ApiVersion::V2);
+        self.for_each_cursor_page(
+            cursor,
+            |cursor| async move {
+                let page = self
+                    .list_companies(Some(limit), cursor.as_deref(), search, segment)
+                    .await?;
+                Ok((page.companies, page.has_next_page.unwrap_or(false), page.cursor))
+            },
+            on_page,
+        )
+        .await
     }
 
     /// List companies using v2 API with cursor-based pagination
@@ -1277,21 +2694,9 @@ impl CannyClient {
         }
 
         // Companies endpoint uses v2 API
-        let base_url = self.api_url.replace("/v1", "/v2");
-        let response = self
-            .client
-            .post(format!("{}/companies/list", base_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        let text = self
+            .send(self.endpoint(ApiVersion::V2, "companies/list"), &body, RequestKind::Read)
+            .await?;
 
         let value: serde_json::Value =
             serde_json::from_str(&text).context("Failed to parse response as JSON")?;
@@ -1301,11 +2706,12 @@ impl CannyClient {
             .ok_or_else(|| anyhow::anyhow!("Expected object response"))?;
 
         // v2 API returns "items" array
-        let companies_value = obj
+        let mut companies_value = obj
             .get("items")
             .or_else(|| obj.get("companies"))
             .cloned()
             .unwrap_or(json!([]));
+        self.drop_invalid::<CannyCompany>(&mut companies_value, "companies");
 
         let companies: Vec<CannyCompany> = serde_json::from_value(companies_value)
             .context("Failed to parse companies from response")?;
@@ -1333,6 +2739,29 @@ impl CannyClient {
         custom_fields: Option<serde_json::Value>,
         created: Option<&str>,
     ) -> Result<()> {
+        let body = self.update_company_body(
+            company_id,
+            name,
+            monthly_spend,
+            custom_fields,
+            created,
+        );
+
+        self.send(self.endpoint(ApiVersion::V1, "companies/update"), &body, RequestKind::Write)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Request body for [`Self::update_company`]
+    pub fn update_company_body(
+        &self,
+        company_id: &str,
+        name: Option<&str>,
+        monthly_spend: Option<f64>,
+        custom_fields: Option<serde_json::Value>,
+        created: Option<&str>,
+    ) -> serde_json::Value {
         let mut body = json!({
             "apiKey": self.api_key,
             "id": company_id,
@@ -1351,47 +2780,25 @@ impl CannyClient {
             body["created"] = json!(c);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/companies/update", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
+        body
+    }
 
-        let status = response.status();
-        let text = response.text().await?;
+    /// Delete a company by ID
+    pub async fn delete_company(&self, company_id: &str) -> Result<()> {
+        let body = self.delete_company_body(company_id);
 
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        self.send(self.endpoint(ApiVersion::V1, "companies/delete"), &body, RequestKind::Write)
+            .await?;
 
         Ok(())
     }
 
-    /// Delete a company by ID
-    pub async fn delete_company(&self, company_id: &str) -> Result<()> {
-        let body = json!({
+    /// Request body for [`Self::delete_company`]
+    pub fn delete_company_body(&self, company_id: &str) -> serde_json::Value {
+        json!({
             "apiKey": self.api_key,
             "id": company_id,
-        });
-
-        let response = self
-            .client
-            .post(format!("{}/companies/delete", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
-
-        Ok(())
+        })
     }
 
     /// Retrieve a single company by ID
@@ -1401,34 +2808,24 @@ impl CannyClient {
             "id": company_id,
         });
 
-        let response = self
-            .client
-            .post(format!("{}/companies/retrieve", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "companies/retrieve"), &body, RequestKind::Read)
+            .await?;
 
         let result: CompanyRetrieveResponse =
             serde_json::from_str(&text).context("Failed to parse response")?;
         Ok(result.company)
     }
 
-    /// List votes for a post or user
+    /// List votes for a post or user, with the raw item count from
+    /// `parse_list`
     pub async fn list_votes(
         &self,
         post_id: Option<&str>,
         user_id: Option<&str>,
         limit: Option<u32>,
         skip: Option<u32>,
-    ) -> Result<VotesListResponse> {
+    ) -> Result<(VotesListResponse, usize)> {
         let mut body = json!({
             "apiKey": self.api_key,
         });
@@ -1446,22 +2843,32 @@ impl CannyClient {
             body["skip"] = json!(s);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/votes/list", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "votes/list"), &body, RequestKind::Read)
+            .await?;
 
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        self.parse_list::<_, CannyVote>(&text, "votes")
+    }
 
-        serde_json::from_str(&text).context("Failed to parse response")
+    /// List every vote on a post by following `hasMore` until exhausted
+    pub async fn list_all_votes(&self, post_id: &str) -> Result<Vec<CannyVote>> {
+        let mut all_votes = Vec::new();
+        let limit = self.page_size(ApiVersion::V1);
+        self.for_each_skip_page(
+            async |skip| {
+                let (response, raw_count) = self
+                    .list_votes(Some(post_id), None, Some(limit), Some(skip))
+                    .await?;
+                Ok((response.votes, response.has_more, raw_count))
+            },
+            async |items| {
+                all_votes.extend(items);
+                Ok(())
+            },
+        )
+        .await?;
+
+        Ok(all_votes)
     }
 
     /// Retrieve a single vote by ID
@@ -1471,20 +2878,9 @@ impl CannyClient {
             "id": vote_id,
         });
 
-        let response = self
-            .client
-            .post(format!("{}/votes/retrieve", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "votes/retrieve"), &body, RequestKind::Read)
+            .await?;
 
         let result: VoteRetrieveResponse =
             serde_json::from_str(&text).context("Failed to parse response")?;
@@ -1492,63 +2888,71 @@ impl CannyClient {
     }
 
     /// Create a vote on a post
-    pub async fn create_vote(&self, post_id: &str, user_id: &str) -> Result<()> {
-        let body = json!({
+    pub async fn create_vote(
+        &self,
+        post_id: &str,
+        user_id: &str,
+        by_id: Option<&str>,
+        created_at: Option<&str>,
+    ) -> Result<()> {
+        let body = self.create_vote_body(post_id, user_id, by_id, created_at);
+
+        self.send(self.endpoint(ApiVersion::V1, "votes/create"), &body, RequestKind::Write)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Request body for [`Self::create_vote`]
+    pub fn create_vote_body(
+        &self,
+        post_id: &str,
+        user_id: &str,
+        by_id: Option<&str>,
+        created_at: Option<&str>,
+    ) -> serde_json::Value {
+        let mut body = json!({
             "apiKey": self.api_key,
             "postID": post_id,
             "userID": user_id,
         });
 
-        let response = self
-            .client
-            .post(format!("{}/votes/create", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
+        if let Some(b) = by_id {
+            body["byID"] = json!(b);
+        }
+        if let Some(c) = created_at {
+            body["createdAt"] = json!(c);
         }
 
-        Ok(())
+        body
     }
 
     /// Delete a vote by ID
     pub async fn delete_vote(&self, vote_id: &str) -> Result<()> {
-        let body = json!({
-            "apiKey": self.api_key,
-            "voteID": vote_id,
-        });
-
-        let response = self
-            .client
-            .post(format!("{}/votes/delete", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
+        let body = self.delete_vote_body(vote_id);
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        self.send(self.endpoint(ApiVersion::V1, "votes/delete"), &body, RequestKind::Write)
+            .await?;
 
         Ok(())
     }
 
-    /// List status changes for a board
+    /// Request body for [`Self::delete_vote`]
+    pub fn delete_vote_body(&self, vote_id: &str) -> serde_json::Value {
+        json!({
+            "apiKey": self.api_key,
+            "voteID": vote_id,
+        })
+    }
+
+    /// List status changes for a board, with the raw item count from
+    /// `parse_list`
     pub async fn list_status_changes(
         &self,
         board_id: &str,
         limit: Option<u32>,
         skip: Option<u32>,
-    ) -> Result<StatusChangesListResponse> {
+    ) -> Result<(StatusChangesListResponse, usize)> {
         let mut body = json!({
             "apiKey": self.api_key,
             "boardID": board_id,
@@ -1561,22 +2965,32 @@ impl CannyClient {
             body["skip"] = json!(s);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/status_changes/list", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "status_changes/list"), &body, RequestKind::Read)
+            .await?;
 
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        self.parse_list::<_, CannyStatusChange>(&text, "statusChanges")
+    }
 
-        serde_json::from_str(&text).context("Failed to parse response")
+    /// List every status change on a board by following `hasMore` until
+    /// exhausted
+    pub async fn list_all_status_changes(&self, board_id: &str) -> Result<Vec<CannyStatusChange>> {
+        let mut all_changes = Vec::new();
+        let limit = self.page_size(ApiVersion::V1);
+        self.for_each_skip_page(
+            async |skip| {
+                let (response, raw_count) =
+                    self.list_status_changes(board_id, Some(limit), Some(skip)).await?;
+                Ok((response.status_changes, response.has_more, raw_count))
+            },
+            async |items| {
+                all_changes.extend(items);
+                Ok(())
+            },
+        )
+        .await?;
+
+        Ok(all_changes)
     }
 
     /// List changelog entries
@@ -1608,22 +3022,11 @@ impl CannyClient {
             body["sort"] = json!(s);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/entries/list", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "entries/list"), &body, RequestKind::Read)
+            .await?;
 
-        serde_json::from_str(&text).context("Failed to parse response")
+        self.parse_list::<_, CannyEntry>(&text, "entries").map(|(response, _)| response)
     }
 
     /// Retrieve a single changelog entry by ID
@@ -1633,20 +3036,9 @@ impl CannyClient {
             "id": entry_id,
         });
 
-        let response = self
-            .client
-            .post(format!("{}/entries/retrieve", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "entries/retrieve"), &body, RequestKind::Read)
+            .await?;
 
         let result: EntryRetrieveResponse =
             serde_json::from_str(&text).context("Failed to parse response")?;
@@ -1655,30 +3047,24 @@ impl CannyClient {
 
     /// Delete a changelog entry by ID
     pub async fn delete_entry(&self, entry_id: &str) -> Result<()> {
-        let body = json!({
-            "apiKey": self.api_key,
-            "entryID": entry_id,
-        });
+        let body = self.delete_entry_body(entry_id);
 
-        let response = self
-            .client
-            .post(format!("{}/entries/delete", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        self.send(self.endpoint(ApiVersion::V1, "entries/delete"), &body, RequestKind::Write)
+            .await?;
 
         Ok(())
     }
 
+    /// Request body for [`Self::delete_entry`]
+    pub fn delete_entry_body(&self, entry_id: &str) -> serde_json::Value {
+        json!({
+            "apiKey": self.api_key,
+            "entryID": entry_id,
+        })
+    }
+
     /// Create a changelog entry
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_entry(
         &self,
         title: &str,
@@ -1691,6 +3077,45 @@ impl CannyClient {
         published_on: Option<&str>,
         scheduled_for: Option<&str>,
     ) -> Result<String> {
+        let body = self.create_entry_body(
+            title,
+            details,
+            entry_type,
+            published,
+            notify,
+            post_ids,
+            label_ids,
+            published_on,
+            scheduled_for,
+        );
+
+        let text = self
+            .send(
+                self.endpoint(ApiVersion::V1, "entries/create"),
+                &body,
+                RequestKind::Write,
+            )
+            .await?;
+
+        let result: CreateResponse =
+            serde_json::from_str(&text).context("Failed to parse response")?;
+        Ok(result.id)
+    }
+
+    /// Request body for [`Self::create_entry`]
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_entry_body(
+        &self,
+        title: &str,
+        details: Option<&str>,
+        entry_type: Option<&str>,
+        published: Option<bool>,
+        notify: Option<bool>,
+        post_ids: Option<Vec<&str>>,
+        label_ids: Option<Vec<&str>>,
+        published_on: Option<&str>,
+        scheduled_for: Option<&str>,
+    ) -> serde_json::Value {
         let mut body = json!({
             "apiKey": self.api_key,
             "title": title,
@@ -1721,27 +3146,11 @@ impl CannyClient {
             body["scheduledFor"] = json!(s);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/entries/create", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
-
-        let result: CreateResponse =
-            serde_json::from_str(&text).context("Failed to parse response")?;
-        Ok(result.id)
+        body
     }
 
     /// Update a changelog entry
+    #[allow(clippy::too_many_arguments)]
     pub async fn update_entry(
         &self,
         entry_id: &str,
@@ -1752,6 +3161,34 @@ impl CannyClient {
         notify: Option<bool>,
         label_ids: Option<Vec<&str>>,
     ) -> Result<()> {
+        let body = self.update_entry_body(
+            entry_id,
+            title,
+            details,
+            entry_type,
+            published,
+            notify,
+            label_ids,
+        );
+
+        self.send(self.endpoint(ApiVersion::V1, "entries/update"), &body, RequestKind::Write)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Request body for [`Self::update_entry`]
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_entry_body(
+        &self,
+        entry_id: &str,
+        title: Option<&str>,
+        details: Option<&str>,
+        entry_type: Option<&str>,
+        published: Option<bool>,
+        notify: Option<bool>,
+        label_ids: Option<Vec<&str>>,
+    ) -> serde_json::Value {
         let mut body = json!({
             "apiKey": self.api_key,
             "entryID": entry_id,
@@ -1776,22 +3213,43 @@ impl CannyClient {
             body["labelIDs"] = json!(ids);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/entries/update", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
+        body
+    }
+
+    /// List the labels available for changelog entries
+    pub async fn list_entry_labels(&self) -> Result<Vec<CannyEntryLabel>> {
+        let body = json!({
+            "apiKey": self.api_key,
+        });
 
-        let status = response.status();
-        let text = response.text().await?;
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "entries/labels/list"), &body, RequestKind::Read)
+            .await?;
 
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        let result: EntryLabelsListResponse =
+            serde_json::from_str(&text).context("Failed to parse response")?;
+        Ok(result.labels)
+    }
 
-        Ok(())
+    /// Create a changelog label
+    pub async fn create_entry_label(&self, name: &str) -> Result<String> {
+        let body = self.create_entry_label_body(name);
+
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "entries/labels/create"), &body, RequestKind::Write)
+            .await?;
+
+        let result: CreateResponse =
+            serde_json::from_str(&text).context("Failed to parse response")?;
+        Ok(result.id)
+    }
+
+    /// Request body for [`Self::create_entry_label`]
+    pub fn create_entry_label_body(&self, name: &str) -> serde_json::Value {
+        json!({
+            "apiKey": self.api_key,
+            "name": name,
+        })
     }
 
     /// List opportunities for a post
@@ -1813,22 +3271,11 @@ impl CannyClient {
             body["skip"] = json!(s);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/opportunities/list", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "opportunities/list"), &body, RequestKind::Read)
+            .await?;
 
-        serde_json::from_str(&text).context("Failed to parse response")
+        self.parse_list::<_, CannyOpportunity>(&text, "opportunities").map(|(response, _)| response)
     }
 
     /// List groups
@@ -1848,22 +3295,34 @@ impl CannyClient {
             body["cursor"] = json!(c);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/groups/list", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "groups/list"), &body, RequestKind::Read)
+            .await?;
 
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        self.parse_list::<_, CannyGroup>(&text, "groups").map(|(response, _)| response)
+    }
 
-        serde_json::from_str(&text).context("Failed to parse response")
+    /// Hand each page of groups to `on_page` as it's fetched, starting at
+    /// `cursor` (None for the first page). Returns the cursor to resume from
+    /// later; see [`Self::for_each_cursor_page`].
+    pub async fn for_each_group_page<F>(
+        &self,
+        cursor: Option<String>,
+        on_page: F,
+    ) -> Result<Option<String>>
+    where
+        F: FnMut(Vec<CannyGroup>) -> Result<()>,
+    {
+        let limit = self.page_size(ApiVersion::V1);
+        self.for_each_cursor_page(
+            cursor,
+            |cursor| async move {
+                let page = self.list_groups(Some(limit), cursor.as_deref()).await?;
+                Ok((page.groups, page.has_more, page.cursor))
+            },
+            on_page,
+        )
+        .await
     }
 
     /// Retrieve a single group by ID or URL name
@@ -1883,20 +3342,9 @@ impl CannyClient {
             body["urlName"] = json!(name);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/groups/retrieve", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "groups/retrieve"), &body, RequestKind::Read)
+            .await?;
 
         let result: GroupRetrieveResponse =
             serde_json::from_str(&text).context("Failed to parse response")?;
@@ -1924,22 +3372,35 @@ impl CannyClient {
             body["ideaID"] = json!(i);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/insights/list", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "insights/list"), &body, RequestKind::Read)
+            .await?;
 
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        self.parse_list::<_, CannyInsight>(&text, "insights").map(|(response, _)| response)
+    }
 
-        serde_json::from_str(&text).context("Failed to parse response")
+    /// Hand each page of insights to `on_page` as it's fetched, starting at
+    /// `cursor` (None for the first page). Returns the cursor to resume from
+    /// later; see [`Self::for_each_cursor_page`].
+    pub async fn for_each_insight_page<F>(
+        &self,
+        idea_id: Option<&str>,
+        cursor: Option<String>,
+        on_page: F,
+    ) -> Result<Option<String>>
+    where
+        F: FnMut(Vec<CannyInsight>) -> Result<()>,
+    {
+        let limit = self.page_size(ApiVersion::V1);
+        self.for_each_cursor_page(
+            cursor,
+            |cursor| async move {
+                let page = self.list_insights(Some(limit), cursor.as_deref(), idea_id).await?;
+                Ok((page.insights, page.has_more, page.cursor))
+            },
+            on_page,
+        )
+        .await
     }
 
     /// Retrieve a single insight by ID
@@ -1949,20 +3410,9 @@ impl CannyClient {
             "id": insight_id,
         });
 
-        let response = self
-            .client
-            .post(format!("{}/insights/retrieve", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "insights/retrieve"), &body, RequestKind::Read)
+            .await?;
 
         let result: InsightRetrieveResponse =
             serde_json::from_str(&text).context("Failed to parse response")?;
@@ -1994,22 +3444,38 @@ impl CannyClient {
             body["search"] = json!(s);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/ideas/list", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "ideas/list"), &body, RequestKind::Read)
+            .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        self.parse_list::<_, CannyIdea>(&text, "ideas").map(|(response, _)| response)
+    }
 
-        serde_json::from_str(&text).context("Failed to parse response")
+    /// Hand each page of ideas to `on_page` as it's fetched, starting at
+    /// `cursor` (None for the first page). Returns the cursor to resume from
+    /// later; see [`Self::for_each_cursor_page`].
+    pub async fn for_each_idea_page<F>(
+        &self,
+        parent_id: Option<&str>,
+        search: Option<&str>,
+        cursor: Option<String>,
+        on_page: F,
+    ) -> Result<Option<String>>
+    where
+        F: FnMut(Vec<CannyIdea>) -> Result<()>,
+    {
+        let limit = self.page_size(ApiVersion::V1);
+        self.for_each_cursor_page(
+            cursor,
+            |cursor| async move {
+                let page = self
+                    .list_ideas(Some(limit), cursor.as_deref(), parent_id, search)
+                    .await?;
+                Ok((page.ideas, page.has_more, page.cursor))
+            },
+            on_page,
+        )
+        .await
     }
 
     /// Retrieve a single idea by ID or URL name
@@ -2029,20 +3495,9 @@ impl CannyClient {
             body["urlName"] = json!(name);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/ideas/retrieve", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "ideas/retrieve"), &body, RequestKind::Read)
+            .await?;
 
         let result: IdeaRetrieveResponse =
             serde_json::from_str(&text).context("Failed to parse response")?;
@@ -2056,6 +3511,24 @@ impl CannyClient {
         user_id: &str,
         source_url: Option<&str>,
     ) -> Result<String> {
+        let body = self.enqueue_autopilot_feedback_body(feedback, user_id, source_url);
+
+        let text = self
+            .send(self.endpoint(ApiVersion::V1, "autopilot/enqueue"), &body, RequestKind::Write)
+            .await?;
+
+        let result: AutopilotEnqueueResponse =
+            serde_json::from_str(&text).context("Failed to parse response")?;
+        Ok(result.id)
+    }
+
+    /// Request body for [`Self::enqueue_autopilot_feedback`]
+    pub fn enqueue_autopilot_feedback_body(
+        &self,
+        feedback: &str,
+        user_id: &str,
+        source_url: Option<&str>,
+    ) -> serde_json::Value {
         let mut body = json!({
             "apiKey": self.api_key,
             "feedback": feedback,
@@ -2066,23 +3539,6 @@ impl CannyClient {
             body["sourceURL"] = json!(url);
         }
 
-        let response = self
-            .client
-            .post(format!("{}/autopilot/enqueue", self.api_url))
-            .json(&body)
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if !status.is_success() {
-            anyhow::bail!("API error ({}): {}", status, text);
-        }
-
-        let result: AutopilotEnqueueResponse =
-            serde_json::from_str(&text).context("Failed to parse response")?;
-        Ok(result.id)
+        body
     }
 }
@@ -0,0 +1,207 @@
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use clap::Command;
+use colored::*;
+
+use crate::credentials;
+
+/// Prefix of keys in the config file's `[defaults]` table; the rest of the
+/// key is the flag it applies to, e.g. `default_board_id` for `--board-id`
+const DEFAULT_KEY_PREFIX: &str = "default_";
+
+/// Flags that can be given a default in the config file
+const DEFAULTABLE_FLAGS: &[&str] = &["board_id", "limit", "sort"];
+
+/// Env file loaded when `--env-file` isn't given, if it exists
+const DEFAULT_ENV_FILE: &str = ".env";
+
+/// Only variables with this prefix are loaded from an env file, so a
+/// project's `.env` can't change unrelated settings such as proxies
+const ENV_FILE_PREFIX: &str = "CANNY_";
+
+/// Variables the default `.env` may only set when its own `CANNY_API_KEY`
+/// is the key in use. Otherwise a `.env` in any checked-out repository could
+/// send the stored key to another host, or append the audit log to any file.
+const KEY_BOUND_VARS: &[&str] = &["CANNY_API_URL", "CANNY_AUDIT_LOG"];
+
+/// The env file to load, found in the raw command-line arguments before
+/// clap parses them (parsing reads the environment): the `--env-file` path,
+/// if given, and whether `--api-key` was given
+pub fn env_file_args(args: impl IntoIterator<Item = OsString>) -> (Option<PathBuf>, bool) {
+    let mut env_file = None;
+    let mut api_key_flag = false;
+    let mut args = args.into_iter().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            break;
+        }
+        if arg == "--env-file" {
+            env_file = args.next().map(PathBuf::from);
+        } else if let Some(path) = arg.to_str().and_then(|arg| arg.strip_prefix("--env-file=")) {
+            env_file = Some(PathBuf::from(path));
+        } else if arg == "--api-key"
+            || arg.to_str().is_some_and(|arg| arg.starts_with("--api-key="))
+        {
+            api_key_flag = true;
+        }
+    }
+    (env_file, api_key_flag)
+}
+
+/// Load `CANNY_*` variables from a dotenv file into the environment,
+/// leaving any already set alone. Without a path, `.env` in the current
+/// directory is loaded if present, and a malformed one is only warned about;
+/// it can't set `KEY_BOUND_VARS` unless it supplies the API key in use
+/// (none is set in the environment and `api_key_flag` is false).
+/// Must run before any other threads start and before the command line is
+/// parsed, which reads these variables.
+pub fn load_env_file(path: Option<&Path>, api_key_flag: bool) -> Result<()> {
+    let (path, explicit) = match path {
+        Some(path) => (path, true),
+        None => (Path::new(DEFAULT_ENV_FILE), false),
+    };
+    let vars = match dotenvy::from_path_iter(path) {
+        Ok(vars) => vars,
+        Err(dotenvy::Error::Io(e)) if !explicit && e.kind() == std::io::ErrorKind::NotFound => {
+            return Ok(());
+        }
+        Err(e) => return env_file_error(e, path, explicit),
+    };
+
+    let mut loaded = Vec::new();
+    for var in vars {
+        match var {
+            Ok(var) => loaded.push(var),
+            Err(e) => return env_file_error(e, path, explicit),
+        }
+    }
+    let supplies_key = !api_key_flag
+        && std::env::var_os("CANNY_API_KEY").is_none()
+        && loaded.iter().any(|(key, _)| key == "CANNY_API_KEY");
+    for (key, value) in loaded {
+        if !key.starts_with(ENV_FILE_PREFIX) || std::env::var_os(&key).is_some() {
+            continue;
+        }
+        if !explicit && !supplies_key && KEY_BOUND_VARS.contains(&key.as_str()) {
+            eprintln!(
+                "{} ignoring {} from {}, which doesn't set the API key in use \
+                 (pass --env-file to trust it)",
+                "warning:".yellow(),
+                key,
+                path.display()
+            );
+            continue;
+        }
+        std::env::set_var(key, value);
+    }
+    Ok(())
+}
+
+fn env_file_error(error: dotenvy::Error, path: &Path, explicit: bool) -> Result<()> {
+    if explicit {
+        return Err(error).with_context(|| format!("Failed to read env file {}", path.display()));
+    }
+    eprintln!("{} ignoring {}: {}", "warning:".yellow(), path.display(), error);
+    Ok(())
+}
+
+/// Load the `[defaults]` table from the config file as (argument ID, value)
+/// pairs. A missing file has no defaults; an unreadable file, or a key that
+/// can't be used, is skipped with a warning.
+pub fn load_defaults() -> Vec<(String, String)> {
+    let Some(path) = credentials::config_file_path() else {
+        return Vec::new();
+    };
+    let table = match credentials::read_config_table(&path) {
+        Ok(table) => table,
+        Err(e) => {
+            eprintln!("{} ignoring config defaults: {:#}", "warning:".yellow(), e);
+            return Vec::new();
+        }
+    };
+    let Some(defaults) = table.get("defaults").and_then(|v| v.as_table()) else {
+        return Vec::new();
+    };
+
+    defaults
+        .iter()
+        .filter_map(|(key, value)| {
+            let id = key.strip_prefix(DEFAULT_KEY_PREFIX).map(|id| id.replace('-', "_"));
+            let Some(id) = id.filter(|id| DEFAULTABLE_FLAGS.contains(&id.as_str())) else {
+                eprintln!(
+                    "{} ignoring unknown key '{}' in [defaults] (expected one of: {})",
+                    "warning:".yellow(),
+                    key,
+                    DEFAULTABLE_FLAGS
+                        .iter()
+                        .map(|flag| format!("{}{}", DEFAULT_KEY_PREFIX, flag))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+                return None;
+            };
+            let value = match value {
+                toml::Value::String(s) => s.clone(),
+                toml::Value::Integer(_) | toml::Value::Float(_) | toml::Value::Boolean(_) => {
+                    value.to_string()
+                }
+                _ => {
+                    eprintln!(
+                        "{} ignoring '{}' in [defaults]: expected a string or number",
+                        "warning:".yellow(),
+                        key
+                    );
+                    return None;
+                }
+            };
+            Some((id, value))
+        })
+        .collect()
+}
+
+/// Whether a default for the argument `id` applies to the subcommand at
+/// `path`, e.g. "posts list". Some commands reuse a flag name for something
+/// else, like `users list --limit`, which caps the total rather than the
+/// page, so each default is kept to the commands it was meant for.
+fn default_applies(id: &str, path: &str) -> bool {
+    match id {
+        "board_id" => true,
+        "limit" => path.ends_with(" list") && path != "users list",
+        "sort" => path == "posts list",
+        _ => false,
+    }
+}
+
+/// Set config defaults on the matching arguments in the command tree, so
+/// they apply only when the flag (or its environment variable) isn't given.
+/// Arguments given a default are no longer required, and defaults that
+/// aren't one of an argument's allowed values are skipped for that argument.
+pub fn apply_defaults(cmd: Command, defaults: &[(String, String)]) -> Command {
+    apply_defaults_at(cmd, defaults, "")
+}
+
+fn apply_defaults_at(mut cmd: Command, defaults: &[(String, String)], path: &str) -> Command {
+    for (id, value) in defaults {
+        let applies = default_applies(id, path)
+            && cmd.get_arguments().any(|arg| {
+                arg.get_id() == id.as_str()
+                    && arg.get_action().takes_values()
+                    && (arg.get_possible_values().is_empty()
+                        || arg.get_possible_values().iter().any(|v| v.matches(value, true)))
+            });
+        if applies {
+            cmd = cmd.mut_arg(id, |arg| arg.default_value(value.clone()).required(false));
+        }
+    }
+
+    let subcommands: Vec<String> =
+        cmd.get_subcommands().map(|sub| sub.get_name().to_string()).collect();
+    for name in subcommands {
+        let sub_path = if path.is_empty() { name.clone() } else { format!("{} {}", path, name) };
+        cmd = cmd.mut_subcommand(&name, |sub| apply_defaults_at(sub, defaults, &sub_path));
+    }
+
+    cmd
+}
@@ -1,34 +1,368 @@
-use anyhow::Result;
-use security_framework::passwords::{
-    delete_generic_password, get_generic_password, set_generic_password,
-};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
 
 const KEYCHAIN_SERVICE: &str = "canny-cli";
 const KEYCHAIN_ACCOUNT_API_KEY: &str = "api-key";
 const KEYCHAIN_ACCOUNT_API_URL: &str = "api-url";
 
-/// Resolve the API key using the following priority:
+/// Account holding the newline-separated list of profiles with stored credentials
+const KEYCHAIN_ACCOUNT_PROFILES: &str = "profiles";
+
+/// Account name used by pre-auth versions of the CLI
+const KEYCHAIN_ACCOUNT_LEGACY: &str = "default";
+
+/// Profile used when neither --profile nor CANNY_PROFILE is set
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// A secure, OS-provided store for the CLI's credentials.
+///
+/// Every entry lives under the `canny-cli` service and is addressed by an
+/// account name (e.g. `api-key:default`).
+trait CredentialStore {
+    /// Human-readable name of the backend, used in error messages
+    fn name(&self) -> &'static str;
+
+    /// Read the value stored for `account`, if any
+    fn get(&self, account: &str) -> Option<String>;
+
+    /// Store `value` for `account`, replacing any existing entry
+    fn set(&self, account: &str, value: &str) -> Result<()>;
+
+    /// Delete the entry stored for `account`
+    fn delete(&self, account: &str) -> Result<()>;
+}
+
+/// Return the credential store for the current platform
+fn platform_store() -> impl CredentialStore {
+    #[cfg(target_os = "macos")]
+    {
+        macos::KeychainStore
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        linux::SecretServiceStore
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        windows::CredentialManagerStore
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+    {
+        unsupported::UnsupportedStore
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod macos {
+    use anyhow::Result;
+    use security_framework::passwords::{
+        delete_generic_password, get_generic_password, set_generic_password,
+    };
+
+    use super::{CredentialStore, KEYCHAIN_SERVICE};
+
+    /// macOS Keychain backend (via Security.framework)
+    pub struct KeychainStore;
+
+    impl CredentialStore for KeychainStore {
+        fn name(&self) -> &'static str {
+            "Keychain"
+        }
+
+        fn get(&self, account: &str) -> Option<String> {
+            let data = get_generic_password(KEYCHAIN_SERVICE, account).ok()?;
+            String::from_utf8(data.to_vec()).ok()
+        }
+
+        fn set(&self, account: &str, value: &str) -> Result<()> {
+            // Delete existing entry if present (set_generic_password fails if it exists)
+            let _ = delete_generic_password(KEYCHAIN_SERVICE, account);
+
+            set_generic_password(KEYCHAIN_SERVICE, account, value.as_bytes())
+                .map_err(|e| anyhow::anyhow!("{}", e))
+        }
+
+        fn delete(&self, account: &str) -> Result<()> {
+            delete_generic_password(KEYCHAIN_SERVICE, account)
+                .map_err(|e| anyhow::anyhow!("{}", e))
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::collections::HashMap;
+
+    use anyhow::Result;
+    use secret_service::blocking::SecretService;
+    use secret_service::EncryptionType;
+
+    use super::{CredentialStore, KEYCHAIN_SERVICE};
+
+    /// freedesktop.org Secret Service backend (GNOME Keyring, KWallet, ...)
+    pub struct SecretServiceStore;
+
+    fn attributes(account: &str) -> HashMap<&str, &str> {
+        HashMap::from([("service", KEYCHAIN_SERVICE), ("account", account)])
+    }
+
+    impl CredentialStore for SecretServiceStore {
+        fn name(&self) -> &'static str {
+            "Secret Service"
+        }
+
+        fn get(&self, account: &str) -> Option<String> {
+            let ss = SecretService::connect(EncryptionType::Dh).ok()?;
+            let results = ss.search_items(attributes(account)).ok()?;
+            let item = results
+                .unlocked
+                .into_iter()
+                .chain(results.locked)
+                .next()?;
+            item.ensure_unlocked().ok()?;
+            let data = item.get_secret().ok()?;
+            String::from_utf8(data).ok()
+        }
+
+        fn set(&self, account: &str, value: &str) -> Result<()> {
+            let ss = SecretService::connect(EncryptionType::Dh)?;
+            let collection = ss.get_default_collection()?;
+            collection.ensure_unlocked()?;
+            collection.create_item(
+                &format!("{} ({})", KEYCHAIN_SERVICE, account),
+                attributes(account),
+                value.as_bytes(),
+                true,
+                "text/plain",
+            )?;
+            Ok(())
+        }
+
+        fn delete(&self, account: &str) -> Result<()> {
+            let ss = SecretService::connect(EncryptionType::Dh)?;
+            let results = ss.search_items(attributes(account))?;
+            let items: Vec<_> = results.unlocked.into_iter().chain(results.locked).collect();
+            if items.is_empty() {
+                anyhow::bail!("no stored entry for {}", account);
+            }
+            for item in items {
+                item.delete()?;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod windows {
+    use anyhow::Result;
+    use windows_sys::Win32::Security::Credentials::{
+        CredDeleteW, CredFree, CredReadW, CredWriteW, CREDENTIALW, CRED_PERSIST_LOCAL_MACHINE,
+        CRED_TYPE_GENERIC,
+    };
+
+    use super::{CredentialStore, KEYCHAIN_SERVICE};
+
+    /// Windows Credential Manager backend (generic credentials)
+    pub struct CredentialManagerStore;
+
+    fn target_name(account: &str) -> Vec<u16> {
+        to_wide(&format!("{}:{}", KEYCHAIN_SERVICE, account))
+    }
+
+    fn to_wide(s: &str) -> Vec<u16> {
+        s.encode_utf16().chain(std::iter::once(0)).collect()
+    }
+
+    impl CredentialStore for CredentialManagerStore {
+        fn name(&self) -> &'static str {
+            "Credential Manager"
+        }
+
+        fn get(&self, account: &str) -> Option<String> {
+            let target = target_name(account);
+            let mut cred: *mut CREDENTIALW = std::ptr::null_mut();
+
+            // SAFETY: `target` is a NUL-terminated UTF-16 string and `cred` is
+            // only dereferenced after CredReadW reports success.
+            unsafe {
+                if CredReadW(target.as_ptr(), CRED_TYPE_GENERIC, 0, &mut cred) == 0 {
+                    return None;
+                }
+                let blob = std::slice::from_raw_parts(
+                    (*cred).CredentialBlob,
+                    (*cred).CredentialBlobSize as usize,
+                );
+                let value = String::from_utf8(blob.to_vec()).ok();
+                CredFree(cred as *const _);
+                value
+            }
+        }
+
+        fn set(&self, account: &str, value: &str) -> Result<()> {
+            let mut target = target_name(account);
+            let mut user = to_wide(account);
+            let mut blob = value.as_bytes().to_vec();
+
+            // SAFETY: all pointers reference buffers that outlive the call.
+            unsafe {
+                let mut cred: CREDENTIALW = std::mem::zeroed();
+                cred.Type = CRED_TYPE_GENERIC;
+                cred.TargetName = target.as_mut_ptr();
+                cred.UserName = user.as_mut_ptr();
+                cred.CredentialBlobSize = blob.len() as u32;
+                cred.CredentialBlob = blob.as_mut_ptr();
+                cred.Persist = CRED_PERSIST_LOCAL_MACHINE;
+
+                if CredWriteW(&cred, 0) == 0 {
+                    anyhow::bail!("{}", std::io::Error::last_os_error());
+                }
+            }
+            Ok(())
+        }
+
+        fn delete(&self, account: &str) -> Result<()> {
+            let target = target_name(account);
+
+            // SAFETY: `target` is a NUL-terminated UTF-16 string.
+            unsafe {
+                if CredDeleteW(target.as_ptr(), CRED_TYPE_GENERIC, 0) == 0 {
+                    anyhow::bail!("{}", std::io::Error::last_os_error());
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+mod unsupported {
+    use anyhow::Result;
+
+    use super::CredentialStore;
+
+    /// Placeholder for platforms without a supported secure store
+    pub struct UnsupportedStore;
+
+    impl CredentialStore for UnsupportedStore {
+        fn name(&self) -> &'static str {
+            "credential store"
+        }
+
+        fn get(&self, _account: &str) -> Option<String> {
+            None
+        }
+
+        fn set(&self, _account: &str, _value: &str) -> Result<()> {
+            anyhow::bail!("no secure credential store is available on this platform")
+        }
+
+        fn delete(&self, _account: &str) -> Result<()> {
+            anyhow::bail!("no secure credential store is available on this platform")
+        }
+    }
+}
+
+/// Where credentials are stored (see `store_credentials`)
+pub enum CredentialBackend {
+    /// The OS credential store (Keychain, Secret Service, Credential Manager)
+    SecureStore(&'static str),
+    /// The plaintext config file fallback
+    ConfigFile(PathBuf),
+}
+
+impl fmt::Display for CredentialBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CredentialBackend::SecureStore(name) => write!(f, "{}", name),
+            CredentialBackend::ConfigFile(path) => write!(f, "config file ({})", path.display()),
+        }
+    }
+}
+
+/// Credentials read from the plaintext config file
+#[derive(Debug, Default)]
+pub struct FileCredentials {
+    pub api_key: Option<String>,
+    pub api_url: Option<String>,
+}
+
+/// Resolve the API key for `profile` using the following priority:
 ///
 /// 1. Explicit key (from --api-key flag or CANNY_API_KEY env var)
-/// 2. Stored key from macOS Keychain (via `canny auth`)
-pub fn resolve_api_key(explicit_key: Option<String>) -> Result<String> {
+/// 2. Key file (from --api-key-file flag or CANNY_API_KEY_FILE env var)
+/// 3. Stored key from the OS credential store (via `canny auth`)
+/// 4. Stored key from the config file (when no credential store is available)
+pub fn resolve_api_key(
+    explicit_key: Option<String>,
+    key_file: Option<&Path>,
+    profile: &str,
+) -> Result<String> {
     if let Some(key) = explicit_key {
         return Ok(key);
     }
+    if let Some(path) = key_file {
+        return read_api_key_file(path);
+    }
 
-    get_stored_api_key().ok_or_else(|| {
-        anyhow::anyhow!(
-            "API key not found. Run `canny auth` to configure, or provide --api-key / set CANNY_API_KEY."
-        )
-    })
+    get_stored(KEYCHAIN_ACCOUNT_API_KEY, profile)
+        .or_else(|| load_credentials_file(profile).and_then(|c| c.api_key))
+        .ok_or_else(|| {
+            if profile == DEFAULT_PROFILE {
+                anyhow::anyhow!(
+                    "API key not found. Run `canny auth` to configure, or provide --api-key / set CANNY_API_KEY."
+                )
+            } else {
+                anyhow::anyhow!(
+                    "API key not found for profile '{}'. Run `canny auth --profile {}` to configure.",
+                    profile,
+                    profile
+                )
+            }
+        })
 }
 
-/// Resolve the API URL using the following priority:
+/// Read an API key from a file such as a mounted secret, trimming whitespace
+pub fn read_api_key_file(path: &Path) -> Result<String> {
+    let key = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read API key file {}", path.display()))?;
+    let key = key.trim();
+    if key.is_empty() {
+        anyhow::bail!("API key file {} is empty", path.display());
+    }
+    Ok(key.to_string())
+}
+
+/// Where the stored API key for `profile` comes from, mirroring the lookup
+/// order of `resolve_api_key`. Returns None when no key is stored.
+pub fn stored_api_key_backend(profile: &str) -> Option<CredentialBackend> {
+    if get_stored(KEYCHAIN_ACCOUNT_API_KEY, profile).is_some() {
+        return Some(CredentialBackend::SecureStore(platform_store().name()));
+    }
+
+    load_credentials_file(profile)
+        .and_then(|c| c.api_key)
+        .and_then(|_| config_file_path())
+        .map(CredentialBackend::ConfigFile)
+}
+
+/// Resolve the API URL for `profile` using the following priority:
 ///
 /// 1. Explicit URL (from --api-url flag, if different from default)
-/// 2. Stored URL from macOS Keychain (via `canny auth`)
-/// 3. Falls back to None (caller should use its default)
-pub fn resolve_api_url(explicit_url: Option<&str>, default_url: &str) -> Option<String> {
+/// 2. Stored URL from the OS credential store (via `canny auth`)
+/// 3. Stored URL from the config file
+/// 4. Falls back to None (caller should use its default)
+pub fn resolve_api_url(
+    explicit_url: Option<&str>,
+    default_url: &str,
+    profile: &str,
+) -> Option<String> {
     // If the user passed a non-default --api-url, use it
     if let Some(url) = explicit_url {
         if url != default_url {
@@ -36,58 +370,305 @@ pub fn resolve_api_url(explicit_url: Option<&str>, default_url: &str) -> Option<
         }
     }
 
-    // Try the keychain
-    if let Some(url) = get_stored_api_url() {
+    // Try the credential store
+    if let Some(url) = get_stored(KEYCHAIN_ACCOUNT_API_URL, profile) {
         return Some(url);
     }
 
-    None
+    // Fall back to the config file
+    load_credentials_file(profile).and_then(|c| c.api_url)
 }
 
-/// Store the API key permanently in the macOS Keychain
-pub fn store_api_key(api_key: &str) -> Result<()> {
-    // Delete existing entry if present (set_generic_password fails if it exists)
-    let _ = delete_generic_password(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT_API_KEY);
+/// Store both credentials for `profile`, preferring the OS credential store
+/// and falling back to the config file when the store can't be reached
+/// (headless CI, containers without a keyring daemon, ...). Returns the
+/// backend used.
+pub fn store_credentials(api_key: &str, api_url: &str, profile: &str) -> Result<CredentialBackend> {
+    let store = platform_store();
+    if store_api_key(api_key, profile).is_ok() && store_api_url(api_url, profile).is_ok() {
+        register_profile(&store, profile);
+        return Ok(CredentialBackend::SecureStore(store.name()));
+    }
 
-    set_generic_password(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT_API_KEY, api_key.as_bytes())
-        .map_err(|e| anyhow::anyhow!("Failed to store API key in Keychain: {}", e))
+    let path = store_credentials_file(api_key, api_url, profile)?;
+    Ok(CredentialBackend::ConfigFile(path))
 }
 
-/// Store the API URL permanently in the macOS Keychain
-pub fn store_api_url(api_url: &str) -> Result<()> {
-    let _ = delete_generic_password(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT_API_URL);
+/// Store the API key for `profile` permanently in the OS credential store
+pub fn store_api_key(api_key: &str, profile: &str) -> Result<()> {
+    let store = platform_store();
+    store
+        .set(&account(KEYCHAIN_ACCOUNT_API_KEY, profile), api_key)
+        .map_err(|e| anyhow::anyhow!("Failed to store API key in {}: {}", store.name(), e))
+}
 
-    set_generic_password(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT_API_URL, api_url.as_bytes())
-        .map_err(|e| anyhow::anyhow!("Failed to store API URL in Keychain: {}", e))
+/// Store the API URL for `profile` permanently in the OS credential store
+pub fn store_api_url(api_url: &str, profile: &str) -> Result<()> {
+    let store = platform_store();
+    store
+        .set(&account(KEYCHAIN_ACCOUNT_API_URL, profile), api_url)
+        .map_err(|e| anyhow::anyhow!("Failed to store API URL in {}: {}", store.name(), e))
 }
 
-/// Clear all stored credentials from the macOS Keychain
-pub fn clear_stored_credentials() -> Result<()> {
+/// Clear the stored credentials for `profile` from the OS credential store
+/// and config file
+pub fn clear_stored_credentials(profile: &str) -> Result<()> {
+    let store = platform_store();
     let mut errors = Vec::new();
 
-    if let Err(e) = delete_generic_password(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT_API_KEY) {
+    if let Err(e) = store.delete(&account(KEYCHAIN_ACCOUNT_API_KEY, profile)) {
         errors.push(format!("API key: {}", e));
     }
-    if let Err(e) = delete_generic_password(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT_API_URL) {
+    if let Err(e) = store.delete(&account(KEYCHAIN_ACCOUNT_API_URL, profile)) {
         errors.push(format!("API URL: {}", e));
     }
 
-    // Also clean up the old "default" account from pre-auth versions
-    let _ = delete_generic_password(KEYCHAIN_SERVICE, "default");
+    if profile == DEFAULT_PROFILE {
+        // Credentials stored before profiles existed belong to the default profile
+        let _ = store.delete(KEYCHAIN_ACCOUNT_API_KEY);
+        let _ = store.delete(KEYCHAIN_ACCOUNT_API_URL);
+
+        // Also clean up the old "default" account from pre-auth versions
+        let _ = store.delete(KEYCHAIN_ACCOUNT_LEGACY);
+    }
+
+    unregister_profile(&store, profile);
+    let cleared_file = clear_credentials_file(profile)?;
 
-    if errors.len() == 2 {
+    if errors.len() == 2 && !cleared_file {
         anyhow::bail!("No stored credentials to clear");
     }
 
     Ok(())
 }
 
-fn get_stored_api_key() -> Option<String> {
-    let data = get_generic_password(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT_API_KEY).ok()?;
-    String::from_utf8(data.to_vec()).ok()
+/// List the names of all profiles with stored credentials, sorted
+pub fn list_profiles() -> Vec<String> {
+    let store = platform_store();
+    let mut profiles = stored_profile_index(&store);
+
+    if store.get(KEYCHAIN_ACCOUNT_API_KEY).is_some() {
+        profiles.push(DEFAULT_PROFILE.to_string());
+    }
+
+    if let Some(table) = config_file_path().and_then(|p| read_config_table(&p).ok()) {
+        if table.contains_key("api_key") {
+            profiles.push(DEFAULT_PROFILE.to_string());
+        }
+        if let Some(named) = table.get("profiles").and_then(|v| v.as_table()) {
+            profiles.extend(named.keys().cloned());
+        }
+    }
+
+    profiles.sort();
+    profiles.dedup();
+    profiles
+}
+
+/// Mask an API key for display, keeping only the first and last 4 characters
+pub fn mask_api_key(api_key: &str) -> String {
+    if api_key.len() > 8 {
+        format!("{}...{}", &api_key[..4], &api_key[api_key.len() - 4..])
+    } else {
+        "****".to_string()
+    }
+}
+
+/// Path to the config file: `$XDG_CONFIG_HOME/canny/config.toml`, falling
+/// back to `~/.config/canny/config.toml`
+pub fn config_file_path() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))?;
+    Some(base.join("canny").join("config.toml"))
+}
+
+/// Store the API key and URL for `profile` in the plaintext config file,
+/// preserving any other settings in it. The default profile lives at the top
+/// level; others live under `[profiles.<name>]`. The file is created with
+/// `0600` permissions on Unix.
+pub fn store_credentials_file(api_key: &str, api_url: &str, profile: &str) -> Result<PathBuf> {
+    let path = config_file_path()
+        .ok_or_else(|| anyhow::anyhow!("Could not determine config directory (set HOME or XDG_CONFIG_HOME)"))?;
+
+    let mut table = read_config_table(&path)?;
+    let section = profile_section_mut(&mut table, profile);
+    section.insert("api_key".to_string(), toml::Value::String(api_key.to_string()));
+    section.insert("api_url".to_string(), toml::Value::String(api_url.to_string()));
+    write_config_table(&path, &table)?;
+
+    Ok(path)
+}
+
+/// Load credentials for `profile` from the plaintext config file, if it exists
+pub fn load_credentials_file(profile: &str) -> Option<FileCredentials> {
+    let path = config_file_path()?;
+    let table = read_config_table(&path).ok()?;
+    let section = profile_section(&table, profile)?;
+
+    let get = |key: &str| section.get(key).and_then(|v| v.as_str()).map(|s| s.to_string());
+    Some(FileCredentials {
+        api_key: get("api_key"),
+        api_url: get("api_url"),
+    })
 }
 
-fn get_stored_api_url() -> Option<String> {
-    let data = get_generic_password(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT_API_URL).ok()?;
-    String::from_utf8(data.to_vec()).ok()
+/// Remove credentials for `profile` from the config file. Returns whether
+/// anything was removed.
+fn clear_credentials_file(profile: &str) -> Result<bool> {
+    let Some(path) = config_file_path() else {
+        return Ok(false);
+    };
+    if !path.exists() {
+        return Ok(false);
+    }
+
+    let mut table = read_config_table(&path)?;
+    let removed = if profile == DEFAULT_PROFILE {
+        let removed_key = table.remove("api_key").is_some();
+        let removed_url = table.remove("api_url").is_some();
+        removed_key || removed_url
+    } else {
+        let removed = table
+            .get_mut("profiles")
+            .and_then(|v| v.as_table_mut())
+            .and_then(|profiles| profiles.remove(profile))
+            .is_some();
+        if table
+            .get("profiles")
+            .and_then(|v| v.as_table())
+            .is_some_and(|profiles| profiles.is_empty())
+        {
+            table.remove("profiles");
+        }
+        removed
+    };
+    if removed {
+        write_config_table(&path, &table)?;
+    }
+
+    Ok(removed)
+}
+
+fn profile_section<'a>(table: &'a toml::Table, profile: &str) -> Option<&'a toml::Table> {
+    if profile == DEFAULT_PROFILE {
+        return Some(table);
+    }
+    table.get("profiles")?.as_table()?.get(profile)?.as_table()
+}
+
+fn profile_section_mut<'a>(table: &'a mut toml::Table, profile: &str) -> &'a mut toml::Table {
+    if profile == DEFAULT_PROFILE {
+        return table;
+    }
+    let profiles = table
+        .entry("profiles")
+        .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+    if !profiles.is_table() {
+        *profiles = toml::Value::Table(toml::Table::new());
+    }
+    let profiles = profiles.as_table_mut().expect("profiles is a table");
+
+    let section = profiles
+        .entry(profile)
+        .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+    if !section.is_table() {
+        *section = toml::Value::Table(toml::Table::new());
+    }
+    section.as_table_mut().expect("profile section is a table")
+}
+
+/// Read the config file as a TOML table (empty when the file does not exist)
+pub fn read_config_table(path: &Path) -> Result<toml::Table> {
+    if !path.exists() {
+        return Ok(toml::Table::new());
+    }
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    contents
+        .parse::<toml::Table>()
+        .with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+fn write_config_table(path: &Path, table: &toml::Table) -> Result<()> {
+    use std::io::Write;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    let mut file = options
+        .open(path)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    // `mode` only applies on creation, so tighten permissions on existing files too
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+    }
+
+    file.write_all(toml::to_string(table)?.as_bytes())
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Credential store account name for `base` in `profile` (e.g. `api-key:staging`)
+fn account(base: &str, profile: &str) -> String {
+    format!("{}:{}", base, profile)
+}
+
+fn get_stored(base: &str, profile: &str) -> Option<String> {
+    let store = platform_store();
+    store.get(&account(base, profile)).or_else(|| {
+        // Credentials stored before profiles existed belong to the default profile
+        if profile == DEFAULT_PROFILE {
+            store.get(base)
+        } else {
+            None
+        }
+    })
+}
+
+fn stored_profile_index(store: &impl CredentialStore) -> Vec<String> {
+    store
+        .get(KEYCHAIN_ACCOUNT_PROFILES)
+        .map(|index| {
+            index
+                .lines()
+                .filter(|l| !l.is_empty())
+                .map(|l| l.to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn register_profile(store: &impl CredentialStore, profile: &str) {
+    let mut profiles = stored_profile_index(store);
+    if !profiles.iter().any(|p| p == profile) {
+        profiles.push(profile.to_string());
+        let _ = store.set(KEYCHAIN_ACCOUNT_PROFILES, &profiles.join("\n"));
+    }
+}
+
+fn unregister_profile(store: &impl CredentialStore, profile: &str) {
+    let mut profiles = stored_profile_index(store);
+    let before = profiles.len();
+    profiles.retain(|p| p != profile);
+    if profiles.len() != before {
+        let _ = if profiles.is_empty() {
+            store.delete(KEYCHAIN_ACCOUNT_PROFILES)
+        } else {
+            store.set(KEYCHAIN_ACCOUNT_PROFILES, &profiles.join("\n"))
+        };
+    }
 }
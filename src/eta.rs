@@ -0,0 +1,79 @@
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+
+/// When an ETA has passed: the end of the month for `2024-03`, the end of
+/// the day for `2024-03-15`, or the timestamp itself for RFC 3339 values
+pub fn eta_deadline(eta: &str) -> Option<DateTime<Utc>> {
+    let eta = eta.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(eta) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(eta, "%Y-%m-%d") {
+        return Some(date.succ_opt()?.and_time(NaiveTime::MIN).and_utc());
+    }
+    let month = NaiveDate::parse_from_str(&format!("{}-01", eta), "%Y-%m-%d").ok()?;
+    Some(month.checked_add_months(chrono::Months::new(1))?.and_time(NaiveTime::MIN).and_utc())
+}
+
+/// Check an `--eta` flag: a month (`2024-03`), a date (`2024-03-15`) or an
+/// RFC 3339 timestamp, passed through unchanged
+pub fn parse_eta(value: &str) -> Result<String, String> {
+    match eta_deadline(value) {
+        Some(_) => Ok(value.to_string()),
+        None => Err(format!("invalid ETA '{}': expected YYYY-MM, YYYY-MM-DD or RFC 3339", value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(timestamp: &str) -> Option<DateTime<Utc>> {
+        Some(DateTime::parse_from_rfc3339(timestamp).unwrap().with_timezone(&Utc))
+    }
+
+    #[test]
+    fn month_ends_at_the_start_of_the_next() {
+        assert_eq!(eta_deadline("2024-03"), utc("2024-04-01T00:00:00Z"));
+        assert_eq!(eta_deadline("2024-02"), utc("2024-03-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn december_rolls_into_the_next_year() {
+        assert_eq!(eta_deadline("2024-12"), utc("2025-01-01T00:00:00Z"));
+        assert_eq!(eta_deadline("2024-12-31"), utc("2025-01-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn date_ends_at_the_start_of_the_next_day() {
+        assert_eq!(eta_deadline("2024-03-15"), utc("2024-03-16T00:00:00Z"));
+        assert_eq!(eta_deadline("2024-02-29"), utc("2024-03-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn rfc3339_is_taken_as_is() {
+        assert_eq!(eta_deadline("2024-03-15T10:30:00Z"), utc("2024-03-15T10:30:00Z"));
+        assert_eq!(eta_deadline("2024-03-15T10:30:00+02:00"), utc("2024-03-15T08:30:00Z"));
+    }
+
+    #[test]
+    fn surrounding_whitespace_is_ignored() {
+        assert_eq!(eta_deadline(" 2024-03 "), utc("2024-04-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn eta_flags_accept_months_dates_and_timestamps() {
+        assert_eq!(parse_eta("2024-03"), Ok("2024-03".to_string()));
+        assert_eq!(parse_eta("2024-03-15"), Ok("2024-03-15".to_string()));
+        assert!(parse_eta("2024-03-15T10:30:00Z").is_ok());
+        assert!(parse_eta("03/2024").is_err());
+    }
+
+    #[test]
+    fn unparseable_etas_have_no_deadline() {
+        assert_eq!(eta_deadline(""), None);
+        assert_eq!(eta_deadline("soon"), None);
+        assert_eq!(eta_deadline("Q3 2024"), None);
+        assert_eq!(eta_deadline("2024-13"), None);
+        assert_eq!(eta_deadline("2023-02-29"), None);
+    }
+}
@@ -1,13 +1,44 @@
 mod api;
+mod config;
 mod credentials;
+mod eta;
 mod models;
+mod output;
+mod similarity;
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use anyhow::{Context, Result};
-use clap::{Parser, Subcommand};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use clap::parser::ValueSource;
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
 use colored::*;
-
-use api::{CannyClient, DEFAULT_API_URL};
-use models::PostSort;
+use futures::{StreamExt, TryStreamExt};
+
+use api::{
+    ApiVersion, CannyApiError, CannyClient, HttpOptions, RawPrinted, DEFAULT_API_URL,
+    DEFAULT_MAX_RETRIES, DEFAULT_PAGE_SIZE, DEFAULT_TIMEOUT_SECS, DEFAULT_USER_AGENT, EXIT_FAILURE,
+    EXIT_INCOMPLETE, EXIT_NOT_FOUND,
+};
+use models::{CompanySort, CreateReturn, MergedPostSort, OpportunitySort, PostGroup, PostSort};
+use output::{ColorChoice, MarkdownChoice, OutputFormat};
+
+const EXIT_CODES_HELP: &str = "\
+EXIT CODES:
+  0  Success
+  1  Other error
+  2  Authentication failed (invalid or unauthorized API key)
+  3  Not found
+  4  Rate limited
+  5  Request rejected as invalid
+  6  Results incomplete (a page failed after earlier pages were fetched, or
+     --deadline passed)";
+
+/// Maximum characters of a description shown in list views
+const DESCRIPTION_PREVIEW_CHARS: usize = 100;
 
 /// A CLI tool for interacting with the Canny API
 ///
@@ -16,39 +47,200 @@ use models::PostSort;
 ///
 /// AUTHENTICATION (in order of precedence):
 ///   1. --api-key flag / CANNY_API_KEY environment variable
-///   2. macOS Keychain (configured via `canny auth`)
+///   2. --api-key-file flag / CANNY_API_KEY_FILE environment variable
+///   3. OS credential store (configured via `canny auth`)
+///   4. ~/.config/canny/config.toml (fallback when no credential store is available)
 ///
 /// Get your API key from: https://canny.io/api-keys
 ///
+/// DEFAULTS:
+///   Keys in the [defaults] table of ~/.config/canny/config.toml apply to
+///   flags that aren't given on the command line or through their environment
+///   variable: `default_board_id` (any command), `default_limit` (list page
+///   sizes, not `users list`) and `default_sort` (`posts list`), e.g.
+///   `default_board_id = "abc123"` or `default_limit = 50`.
+///
+/// NOTIFICATIONS:
+///   Only these operations can notify anyone, and only when --notify is given:
+///     posts status, posts status-bulk       email the post's voters
+///     comments create                       email the post's voters
+///     changelog create, changelog update    notify changelog subscribers
+///   Canny has no notification option for other writes, e.g. creating or
+///   updating posts, adding votes, or importing.
+///
 /// EXAMPLES:
-///   # Authenticate (stores API key and URL in Keychain)
+///   # Authenticate (stores API key and URL in the OS credential store)
 ///   canny auth
 ///
+///   # Authenticate a second workspace and use it
+///   canny auth --profile staging
+///   canny --profile staging boards list
+///
 ///   # List posts from a board
 ///   canny posts list --board-id abc123
 ///
+///   # Export a board's posts to a spreadsheet
+///   canny posts list --board-id abc123 --all --format csv > posts.csv
+///
 ///   # Create a new post
 ///   canny posts create --board-id abc123 --author-id user456 --title "Feature request"
 ///
+///   # Preview a change without sending it
+///   canny --dry-run posts status --id post123 --changer-id user456 --status complete
+///
 ///   # View comments on a post
 ///   canny comments list --post-id post789
 #[derive(Parser)]
 #[command(name = "canny")]
-#[command(author, version, about, long_about = None)]
+#[command(author, version, about, long_about = None, after_help = EXIT_CODES_HELP)]
 #[command(propagate_version = true)]
 struct Cli {
-    /// Canny API key (defaults to CANNY_API_KEY env var, then Keychain)
+    /// Canny API key (defaults to CANNY_API_KEY env var, then stored credentials)
     #[arg(long, env = "CANNY_API_KEY", global = true, hide_env_values = true)]
     api_key: Option<String>,
 
-    /// Override the Canny API URL
-    #[arg(long, global = true)]
+    /// Read the API key from this file, e.g. a mounted secret (used when no --api-key is given)
+    #[arg(long, env = "CANNY_API_KEY_FILE", global = true, value_name = "PATH")]
+    api_key_file: Option<PathBuf>,
+
+    /// Override the Canny API URL (defaults to CANNY_API_URL env var, then stored credentials)
+    #[arg(long, env = "CANNY_API_URL", global = true)]
     api_url: Option<String>,
 
-    /// Output as JSON instead of formatted text
-    #[arg(long, global = true)]
+    /// Force every request onto one API version (default: each endpoint's own, v1 or v2)
+    #[arg(long, global = true, value_enum)]
+    api_version: Option<ApiVersion>,
+
+    /// Credentials profile to use (defaults to CANNY_PROFILE env var, then "default")
+    #[arg(long, env = "CANNY_PROFILE", global = true, default_value = credentials::DEFAULT_PROFILE)]
+    profile: String,
+
+    /// Load CANNY_* variables such as CANNY_API_KEY, CANNY_API_URL and
+    /// CANNY_PROFILE from this dotenv file (default: .env, if present, which
+    /// may only set CANNY_API_URL and CANNY_AUDIT_LOG along with the API key
+    /// in use); variables already set in the environment take precedence
+    #[arg(long, global = true, value_name = "PATH")]
+    #[allow(dead_code)] // Read from the raw arguments before parsing
+    env_file: Option<PathBuf>,
+
+    /// Output format
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
+    /// Output as JSON instead of formatted text (alias for --format json)
+    #[arg(long, global = true, conflicts_with = "format")]
     json: bool,
 
+    /// Print only the new ID for creates and nothing for other changes; the exit code reports success
+    #[arg(short, long, global = true, conflicts_with_all = ["json", "format", "template"])]
+    quiet: bool,
+
+    /// Print JSON on a single line instead of pretty-printed
+    #[arg(long, global = true)]
+    compact: bool,
+
+    /// Only include these top-level fields in JSON output (comma-separated)
+    #[arg(long, global = true, value_delimiter = ',', value_name = "FIELDS")]
+    fields: Vec<String>,
+
+    /// Print each item as a line rendered from this template, e.g. "{id}\t{author.name}".
+    /// Applies to list and get output; other commands print their usual JSON
+    #[arg(long, global = true, conflicts_with_all = ["format", "fields"])]
+    template: Option<String>,
+
+    /// When to use colors (auto disables them when piped or NO_COLOR is set)
+    #[arg(long, global = true, value_enum, default_value_t = ColorChoice::Auto)]
+    color: ColorChoice,
+
+    /// Disable colors (alias for --color never)
+    #[arg(long, global = true, conflicts_with = "color")]
+    no_color: bool,
+
+    /// When to render markdown in post and changelog details (auto renders when colors are on)
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        value_name = "WHEN",
+        default_value_t = MarkdownChoice::Auto
+    )]
+    markdown: MarkdownChoice,
+
+    /// Print the API response body as-is for get/list commands instead of formatting it
+    /// (lookups such as --author-email aren't printed; commands that make several requests
+    /// of their own stop after the first)
+    #[arg(long, global = true)]
+    raw: bool,
+
+    /// Skip list items that fail to parse, with a warning, instead of failing
+    /// the whole list
+    #[arg(long, global = true)]
+    skip_invalid: bool,
+
+    /// Print request counts, retries, errors, bytes and timings to stderr
+    /// when the command finishes
+    #[arg(long, global = true)]
+    stats: bool,
+
+    /// Append a JSON record of every create/update/delete request (timestamp,
+    /// path, body with the API key redacted, status and new ID) to this file
+    #[arg(long, env = "CANNY_AUDIT_LOG", global = true, value_name = "PATH")]
+    audit_log: Option<PathBuf>,
+
+    /// Record read requests in the --audit-log too
+    #[arg(long, global = true, requires = "audit_log")]
+    audit_all: bool,
+
+    /// Print the endpoint and body of any create/update/delete request instead of sending it
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    /// Retry rate-limited (429) and server error (5xx) responses up to N times
+    #[arg(long, global = true, value_name = "N", default_value_t = DEFAULT_MAX_RETRIES)]
+    max_retries: u32,
+
+    /// Items per request when fetching every page, clamped to the API maximum
+    /// (100 for v2 endpoints, 10000 for v1)
+    #[arg(
+        long,
+        global = true,
+        value_name = "N",
+        default_value_t = DEFAULT_PAGE_SIZE,
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    page_size: u32,
+
+    /// HTTP request timeout in seconds (0 disables the timeout)
+    #[arg(long, global = true, value_name = "SECONDS", default_value_t = DEFAULT_TIMEOUT_SECS)]
+    timeout: u64,
+
+    /// Overall time budget in seconds for fetching every page; once passed,
+    /// results collected so far are shown with a warning
+    #[arg(long, global = true, value_name = "SECONDS")]
+    deadline: Option<u64>,
+
+    /// Send all requests through this proxy (defaults to HTTPS_PROXY/HTTP_PROXY)
+    #[arg(long, global = true, value_name = "URL")]
+    proxy: Option<String>,
+
+    /// Also trust the root certificates in this PEM file (e.g. a corporate CA)
+    #[arg(long, global = true, value_name = "PATH")]
+    ca_bundle: Option<PathBuf>,
+
+    /// Skip TLS certificate verification, e.g. for a staging proxy with a
+    /// self-signed certificate (testing only; never read from the environment
+    /// or config file)
+    #[arg(long, global = true)]
+    insecure: bool,
+
+    /// User-Agent header to send, e.g. to tag automated traffic
+    #[arg(long, global = true, value_name = "UA", default_value = DEFAULT_USER_AGENT)]
+    user_agent: String,
+
+    /// Log every HTTP request and response (API key redacted) and retry attempts to stderr
+    #[arg(short, long, global = true)]
+    verbose: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -102,6 +294,13 @@ enum Commands {
     #[command(subcommand)]
     Companies(CompaniesCommands),
 
+    /// Manage user segments
+    ///
+    /// Segments group users and companies by rules set up in Canny. Use
+    /// their URL names with `companies list --segment`.
+    #[command(subcommand)]
+    Segments(SegmentsCommands),
+
     /// Manage votes on posts
     ///
     /// Votes represent user support for posts. Users can vote on posts
@@ -156,19 +355,140 @@ enum Commands {
     #[command(subcommand)]
     Autopilot(AutopilotCommands),
 
+    /// Export a board's posts as NDJSON for backup
+    ///
+    /// Pages through every post on the board and writes one JSON object per
+    /// line, so the output streams and never holds the whole board in memory.
+    /// Comments and votes can be embedded in each post object.
+    ///
+    /// EXAMPLES:
+    ///   # Back up a board with all discussion
+    ///   canny export --board-id abc123 --out board.ndjson --include-comments --include-votes
+    ///
+    ///   # Stream posts to another tool
+    ///   canny export --board-id abc123 --out - | jq -r .title
+    Export {
+        /// The ID of the board to export
+        #[arg(long)]
+        board_id: String,
+
+        /// File to write to, or - for stdout
+        #[arg(long)]
+        out: String,
+
+        /// Embed each post's comments as a "comments" array
+        #[arg(long)]
+        include_comments: bool,
+
+        /// Embed each post's votes as a "votes" array
+        #[arg(long)]
+        include_votes: bool,
+    },
+
+    /// Show which credentials are in use and what they can access
+    ///
+    /// Reports the resolved API URL, the masked API key and where it came
+    /// from, how many boards the key can see, and whether v2 endpoints are
+    /// reachable. Useful for debugging credential differences between machines.
+    ///
+    /// EXAMPLES:
+    ///   canny whoami
+    ///   canny whoami --profile staging --json
+    Whoami,
+
+    /// Show the CLI version and build details
+    ///
+    /// Prints the version along with the commit, build date and compiler it
+    /// was built from. `--version` prints just the version.
+    ///
+    /// EXAMPLES:
+    ///   canny version
+    ///   canny version --json
+    Version,
+
+    /// Send a request to any API endpoint
+    ///
+    /// POSTs a JSON body to the endpoint with your API key added and prints
+    /// the response, for endpoints the CLI has no command for. Paths may
+    /// start with v1/ or v2/; otherwise v1 is used.
+    ///
+    /// EXAMPLES:
+    ///   # List boards
+    ///   canny api boards/list
+    ///
+    ///   # Send a body from the command line, a file, or stdin
+    ///   canny api v2/companies/list --data '{"limit": 5}'
+    ///   canny api webhooks/create --data @webhook.json
+    ///   echo '{"id": "abc123"}' | canny api posts/retrieve --data -
+    Api {
+        /// Endpoint path, e.g. segments/list or v2/companies/list
+        path: String,
+
+        /// JSON object to send (@FILE reads a file, - reads stdin; default: {})
+        #[arg(long)]
+        data: Option<String>,
+    },
+
     /// Authenticate with the Canny API
     ///
     /// If already authenticated, shows your current credentials and verifies
     /// them. Use --reset to clear stored credentials and re-authenticate.
+    /// Credentials are stored per profile (see --profile).
     ///
     /// EXAMPLES:
     ///   canny auth
     ///   canny auth --reset
+    ///   canny auth --profile staging
+    ///   canny auth list
+    ///   canny auth export --show-secret
     Auth {
         /// Clear stored credentials and re-authenticate
         #[arg(long)]
         reset: bool,
+
+        #[command(subcommand)]
+        command: Option<AuthCommands>,
+    },
+}
+
+#[derive(Subcommand)]
+enum AuthCommands {
+    /// List all profiles with stored credentials
+    ///
+    /// Shows each profile's API URL and a masked API key. The active profile
+    /// is marked with an asterisk.
+    ///
+    /// EXAMPLES:
+    ///   canny auth list
+    List,
+
+    /// Print the stored credentials so they can be moved to another machine
+    ///
+    /// Prints the profile's stored API URL and unmasked API key as a
+    /// `canny auth import` command to run on the new machine (or as JSON with
+    /// --json). Only stored credentials are exported, not --api-key or
+    /// CANNY_API_KEY.
+    ///
+    /// EXAMPLES:
+    ///   canny auth export --show-secret
+    ///   canny auth export --show-secret --profile staging --json
+    Export {
+        /// Confirm that the API key may be printed in plain text
+        #[arg(long)]
+        show_secret: bool,
     },
+
+    /// Store credentials without prompting
+    ///
+    /// Saves the API key given by --api-key (or --api-key-file, or the
+    /// CANNY_API_KEY environment variable) and the --api-url for the profile,
+    /// in the OS credential store or the config file when no store is
+    /// available. Useful for provisioning new machines.
+    ///
+    /// EXAMPLES:
+    ///   canny auth import --api-key sk_abc123 --api-url https://mycompany.canny.io/api/v1
+    ///   canny auth import --profile staging --api-key-file /run/secrets/canny
+    Import,
 }
 
 #[derive(Subcommand)]
@@ -190,8 +510,38 @@ enum PostsCommands {
     ///   # Filter by author
     ///   canny posts list --board-id abc123 --author-id user456
     ///
+    ///   # Posts from any of several authors
+    ///   canny posts list --board-id abc123 --author-id user456 --author-id user789
+    ///
     ///   # Search for posts
     ///   canny posts list --board-id abc123 --search "dark mode"
+    ///
+    ///   # Fetch every post on the board
+    ///   canny posts list --board-id abc123 --all
+    ///
+    ///   # Posts with 50 or more votes across the whole board
+    ///   canny posts list --board-id abc123 --all --min-score 50
+    ///
+    ///   # Posts created in Q1 2024
+    ///   canny posts list --board-id abc123 --all --created-after 2024-01-01 --created-before 2024-04-01
+    ///
+    ///   # Show posts as an aligned table
+    ///   canny posts list --board-id abc123 --format table
+    ///
+    ///   # Everything still in flight
+    ///   canny posts list --board-id abc123 --all --status-not closed --status-not complete
+    ///
+    ///   # Stream a large board one post per line, page by page
+    ///   canny posts list --board-id abc123 --all --format ndjson > posts.ndjson
+    ///
+    ///   # Prioritize by the revenue behind each post's votes
+    ///   canny posts list --board-id abc123 --all --weight-by-spend --concurrency 8
+    ///
+    ///   # How many open posts there are, for a dashboard
+    ///   canny posts list --board-id abc123 --all --status open --count-only
+    ///
+    ///   # Review a board category by category
+    ///   canny posts list --board-id abc123 --all --group-by category
     List {
         /// The ID of the board to list posts from
         #[arg(long)]
@@ -205,17 +555,23 @@ enum PostsCommands {
         #[arg(long, default_value = "0")]
         skip: u32,
 
-        /// Sort order for posts
-        #[arg(long, value_enum, default_value = "newest")]
-        sort: PostSort,
+        /// Sort order for posts (default: newest; relevance requires --search)
+        #[arg(long, value_enum)]
+        sort: Option<PostSort>,
 
         /// Filter by status (can be specified multiple times)
         #[arg(long)]
         status: Vec<String>,
 
-        /// Filter by author ID
+        /// Exclude posts with this status (can be specified multiple times;
+        /// filtered client-side, after any --status filter)
         #[arg(long)]
-        author_id: Option<String>,
+        status_not: Vec<String>,
+
+        /// Filter by author ID (can be specified multiple times; each author is
+        /// fetched with a separate request and the results are merged)
+        #[arg(long)]
+        author_id: Vec<String>,
 
         /// Search posts by title and content
         #[arg(long)]
@@ -228,13 +584,93 @@ enum PostsCommands {
         /// Filter by tag IDs (can be specified multiple times)
         #[arg(long = "tag-id")]
         tag_ids: Vec<String>,
+
+        /// Fetch all pages (ignores --limit and --skip)
+        #[arg(long)]
+        all: bool,
+
+        /// Print only the number of posts found instead of listing them
+        /// (requires --all)
+        #[arg(long, requires = "all", conflicts_with_all = ["weight_by_spend", "group_by"])]
+        count_only: bool,
+
+        /// Only show posts with at least this many votes (filtered client-side)
+        #[arg(long)]
+        min_score: Option<i32>,
+
+        /// Only show posts with at most this many votes (filtered client-side)
+        #[arg(long)]
+        max_score: Option<i32>,
+
+        /// Only show posts with at least this many comments (filtered client-side)
+        #[arg(long)]
+        min_comments: Option<i32>,
+
+        /// Only show posts created at or after this date (ISO 8601, e.g. 2024-01-01)
+        #[arg(long, value_parser = parse_datetime)]
+        created_after: Option<DateTime<Utc>>,
+
+        /// Only show posts created before this date (ISO 8601, e.g. 2024-04-01)
+        #[arg(long, value_parser = parse_datetime)]
+        created_before: Option<DateTime<Utc>>,
+
+        /// Rank posts by the summed monthly spend of their voters' companies.
+        /// Fetches every company and each post's votes, so it's slow on
+        /// large boards (text and JSON output only)
+        #[arg(long)]
+        weight_by_spend: bool,
+
+        /// Maximum number of posts whose votes are fetched at once with
+        /// --weight-by-spend
+        #[arg(long, default_value = "4", value_parser = clap::value_parser!(u32).range(1..))]
+        concurrency: u32,
+
+        /// Print posts under a header per group, groups alphabetically and
+        /// the highest-scored posts first within each (text output only)
+        #[arg(long, value_enum, conflicts_with = "weight_by_spend")]
+        group_by: Option<PostGroup>,
+    },
+
+    /// List posts across every board
+    ///
+    /// Fetches posts from all boards concurrently and merges them into one
+    /// list, showing which board each post came from. Useful for searching a
+    /// keyword everywhere at once.
+    ///
+    /// EXAMPLES:
+    ///   # Search every board for a keyword
+    ///   canny posts list-all-boards --search "dark mode"
+    ///
+    ///   # Newest open posts across all boards
+    ///   canny posts list-all-boards --status open --sort created
+    ListAllBoards {
+        /// Search posts by title and content
+        #[arg(long)]
+        search: Option<String>,
+
+        /// Filter by status (can be specified multiple times)
+        #[arg(long)]
+        status: Vec<String>,
+
+        /// Maximum number of posts to fetch from each board
+        #[arg(long, default_value = "10")]
+        limit: u32,
+
+        /// Order of the merged results (highest score or newest first)
+        #[arg(long, value_enum, default_value = "score")]
+        sort: MergedPostSort,
+
+        /// Maximum number of boards to fetch at once
+        #[arg(long, default_value = "4", value_parser = clap::value_parser!(u32).range(1..))]
+        concurrency: u32,
     },
 
     /// Retrieve a single post by ID or URL name
     ///
     /// Gets detailed information about a specific post including author,
     /// category, vote count, and more. You can retrieve by ID or by URL name
-    /// (which requires the board ID).
+    /// (which requires the board ID). When run in a terminal without either,
+    /// you are prompted to pick a board and one of its recent posts.
     ///
     /// EXAMPLES:
     ///   # Get by ID
@@ -242,6 +678,15 @@ enum PostsCommands {
     ///
     ///   # Get by URL name (requires board ID)
     ///   canny posts get --url-name my-feature-request --board-id abc123
+    ///
+    ///   # Pick from the board's recent posts interactively
+    ///   canny posts get --board-id abc123
+    ///
+    ///   # Show the post with its discussion
+    ///   canny posts get --id post123 --with-comments
+    ///
+    ///   # See who voted and which companies they belong to
+    ///   canny posts get --id post123 --show-voters
     Get {
         /// The ID of the post to retrieve
         #[arg(long)]
@@ -254,12 +699,22 @@ enum PostsCommands {
         /// The board ID (required when using --url-name)
         #[arg(long)]
         board_id: Option<String>,
+
+        /// Also fetch the post's comments (under "comments" in JSON output)
+        #[arg(long)]
+        with_comments: bool,
+
+        /// Also list the post's voters with their emails and companies
+        /// (under "voters" in JSON output)
+        #[arg(long)]
+        show_voters: bool,
     },
 
     /// Create a new post
     ///
     /// Creates a new post on the specified board. The author must be a valid
-    /// user ID in your Canny account.
+    /// user in your Canny account, given by ID or looked up by email. Canny
+    /// sends no notifications for new posts.
     ///
     /// EXAMPLES:
     ///   # Create a simple post
@@ -270,20 +725,35 @@ enum PostsCommands {
     ///     --title "Add dark mode" \
     ///     --details "It would be great to have a dark theme option" \
     ///     --category-id cat789
+    ///
+    ///   # Create a post for a user known only by email
+    ///   canny posts create --board-id abc123 --author-email jane@example.com --title "Add dark mode"
+    ///
+    ///   # Print the whole new post, including its URL
+    ///   canny posts create --board-id abc123 --author-id user456 --title "Add dark mode" \
+    ///     --return full --json
+    ///
+    ///   # Send a request body written by hand, e.g. for fields without a flag
+    ///   canny posts create --input-json @post.json
     Create {
         /// The ID of the board to create the post on
-        #[arg(long)]
-        board_id: String,
+        #[arg(long, required_unless_present = "input_json")]
+        board_id: Option<String>,
 
         /// The ID of the user creating the post
+        #[arg(long, required_unless_present_any = ["author_email", "input_json"])]
+        author_id: Option<String>,
+
+        /// Email of the user creating the post, looked up to find their ID
+        /// (--author-id takes precedence)
         #[arg(long)]
-        author_id: String,
+        author_email: Option<String>,
 
         /// Title of the post
-        #[arg(long)]
-        title: String,
+        #[arg(long, required_unless_present = "input_json")]
+        title: Option<String>,
 
-        /// Detailed description of the post (supports markdown)
+        /// Detailed description of the post (supports markdown; @FILE reads a file, - reads stdin)
         #[arg(long)]
         details: Option<String>,
 
@@ -299,8 +769,9 @@ enum PostsCommands {
         #[arg(long)]
         custom_fields: Option<String>,
 
-        /// Estimated time of arrival/completion (ISO 8601 format)
-        #[arg(long)]
+        /// Estimated time of arrival/completion (a month such as "2024-03", a date or an
+        /// RFC 3339 timestamp)
+        #[arg(long, value_parser = eta::parse_eta)]
         eta: Option<String>,
 
         /// Whether the ETA should be visible to voters
@@ -316,8 +787,27 @@ enum PostsCommands {
         image_urls: Vec<String>,
 
         /// Post creation timestamp (ISO 8601 format, for imports)
-        #[arg(long)]
+        #[arg(long, value_parser = parse_iso8601)]
         created_at: Option<String>,
+
+        /// Print only the new post's ID, or the full post (fetched again
+        /// after creating it)
+        #[arg(long = "return", value_enum, default_value = "id")]
+        return_mode: CreateReturn,
+
+        /// The whole request body as a JSON object instead of the flags above
+        /// (@FILE reads a file, - reads stdin); must include boardID, authorID
+        /// and title
+        #[arg(
+            long,
+            value_name = "JSON",
+            conflicts_with_all = [
+                "board_id", "author_id", "author_email", "title", "details", "category_id",
+                "by_id", "custom_fields", "eta", "eta_public", "owner_id", "image_urls",
+                "created_at",
+            ]
+        )]
+        input_json: Option<String>,
     },
 
     /// Change the status of a post
@@ -376,7 +866,8 @@ enum PostsCommands {
 
     /// Update a post
     ///
-    /// Updates the title and/or details of an existing post.
+    /// Updates the title and/or details of an existing post. Canny sends no
+    /// notifications for post edits.
     ///
     /// EXAMPLES:
     ///   # Update the title
@@ -387,6 +878,9 @@ enum PostsCommands {
     ///
     ///   # Update both title and details
     ///   canny posts update --id post123 --title "New title" --details "Updated description"
+    ///
+    ///   # Reassign the post to a different owner
+    ///   canny posts update --id post123 --owner-id user456
     Update {
         /// The ID of the post to update
         #[arg(long)]
@@ -396,12 +890,13 @@ enum PostsCommands {
         #[arg(long)]
         title: Option<String>,
 
-        /// New details for the post (supports markdown)
+        /// New details for the post (supports markdown; @FILE reads a file, - reads stdin)
         #[arg(long)]
         details: Option<String>,
 
-        /// Estimated time of arrival/completion (e.g., "2024-03", "Q2 2024")
-        #[arg(long)]
+        /// Estimated time of arrival/completion (a month such as "2024-03", a date or an
+        /// RFC 3339 timestamp)
+        #[arg(long, value_parser = eta::parse_eta)]
         eta: Option<String>,
 
         /// Whether the ETA should be visible to voters
@@ -411,18 +906,222 @@ enum PostsCommands {
         /// Custom fields as JSON object (e.g., '{"priority": "high"}')
         #[arg(long)]
         custom_fields: Option<String>,
+
+        /// Reassign the post to this owner (user ID)
+        #[arg(long)]
+        owner_id: Option<String>,
     },
 
     /// Delete a post
     ///
-    /// Permanently deletes a post by ID.
+    /// Permanently deletes a post by ID, or every post listed in a file.
+    /// When deleting from a file, each ID is attempted even if earlier ones
+    /// fail, and the command exits non-zero if any deletion failed.
     ///
     /// EXAMPLES:
     ///   canny posts delete --id post123
+    ///
+    ///   # Delete every post ID in a file (one per line)
+    ///   canny posts delete --from-file spam-ids.txt --yes
     Delete {
         /// The ID of the post to delete
+        #[arg(long, required_unless_present = "from_file", conflicts_with = "from_file")]
+        id: Option<String>,
+
+        /// Read post IDs to delete from a file, one per line (blank lines and # comments are skipped)
+        #[arg(long, value_name = "PATH")]
+        from_file: Option<PathBuf>,
+
+        /// Skip the confirmation prompt when deleting from a file
+        #[arg(short, long)]
+        yes: bool,
+    },
+
+    /// Change the status of many posts at once
+    ///
+    /// Targets every post ID listed in a file, or every post on a board that
+    /// currently has --filter-status. Each post is attempted even if earlier
+    /// ones fail, and the command exits non-zero if any change failed.
+    /// Combine with the global --dry-run to preview the changes.
+    ///
+    /// EXAMPLES:
+    ///   # Ship everything that was planned for this release
+    ///   canny posts status-bulk --board-id abc123 --filter-status planned \
+    ///     --status complete --changer-id user456 --notify --comment "Out now!"
+    ///
+    ///   # Change the posts listed in a file (one ID per line)
+    ///   canny posts status-bulk --from-file shipped.txt --status complete \
+    ///     --changer-id user456 --yes
+    StatusBulk {
+        /// The ID of the board to select posts from (with --filter-status)
+        #[arg(long)]
+        board_id: Option<String>,
+
+        /// Read post IDs from a file, one per line (blank lines and # comments are skipped)
+        #[arg(
+            long,
+            value_name = "PATH",
+            required_unless_present = "filter_status",
+            conflicts_with = "filter_status"
+        )]
+        from_file: Option<PathBuf>,
+
+        /// Target every post on the board that currently has this status
+        #[arg(long, requires = "board_id")]
+        filter_status: Option<String>,
+
+        /// The ID of the user making the change
+        #[arg(long)]
+        changer_id: String,
+
+        /// New status for the posts
+        #[arg(long)]
+        status: String,
+
+        /// Notify voters about the status change
+        #[arg(long, default_value = "false")]
+        notify: bool,
+
+        /// Add the same comment to every post when changing status
+        #[arg(long)]
+        comment: Option<String>,
+
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+
+    /// Import posts from a CSV or NDJSON file
+    ///
+    /// Creates one post per record. Files ending in .csv are read as CSV with
+    /// a header row; anything else is read as NDJSON (one object per line).
+    /// Recognized fields are title, details, authorID, categoryID, createdAt
+    /// and customFields (a JSON object; a JSON string in CSV). Records missing
+    /// a title or authorID, or with a createdAt that isn't ISO 8601, are
+    /// skipped and reported, and the command exits non-zero if any record was
+    /// skipped or failed. Combine with the global --dry-run to validate a file
+    /// without creating anything.
+    ///
+    /// EXAMPLES:
+    ///   canny posts import --board-id abc123 --file posts.ndjson
+    ///
+    ///   # Check a file before importing it
+    ///   canny --dry-run posts import --board-id abc123 --file legacy.csv
+    Import {
+        /// The ID of the board to create posts on
+        #[arg(long)]
+        board_id: String,
+
+        /// CSV or NDJSON file of posts to import
+        #[arg(long, value_name = "PATH")]
+        file: PathBuf,
+    },
+
+    /// Watch a post for status changes
+    ///
+    /// Polls the post every --interval seconds and prints a line each time
+    /// its status changes. Stops when the status reaches --until, or on Ctrl-C.
+    ///
+    /// EXAMPLES:
+    ///   # Wait for a feature to ship
+    ///   canny posts watch --id post123 --until complete
+    ///
+    ///   # Check every 5 minutes, forever
+    ///   canny posts watch --id post123 --interval 300
+    Watch {
+        /// The ID of the post to watch
+        #[arg(long)]
+        id: String,
+
+        /// Seconds between checks
+        #[arg(long, default_value = "30", value_parser = clap::value_parser!(u64).range(1..))]
+        interval: u64,
+
+        /// Stop once the post reaches this status (e.g. "complete")
+        #[arg(long)]
+        until: Option<String>,
+    },
+
+    /// Summarize a board's posts
+    ///
+    /// Fetches every post on the board and reports counts by status, total
+    /// votes and comments, the average score, and the top posts by score.
+    ///
+    /// EXAMPLES:
+    ///   canny posts stats --board-id abc123
+    ///   canny posts stats --board-id abc123 --json
+    Stats {
+        /// The ID of the board to summarize
+        #[arg(long)]
+        board_id: String,
+    },
+
+    /// List posts whose ETA has passed but aren't complete
+    ///
+    /// Fetches every post on the board and reports the ones with an ETA in
+    /// the past and a status other than complete, most overdue first. A
+    /// month ETA like 2024-03 is overdue once the month is over, and a date
+    /// once the day is over.
+    ///
+    /// EXAMPLES:
+    ///   canny posts overdue --board-id abc123
+    ///   canny posts overdue --board-id abc123 --json
+    Overdue {
+        /// The ID of the board to check
+        #[arg(long)]
+        board_id: String,
+    },
+
+    /// Find existing posts similar to a title, e.g. before filing a new one
+    ///
+    /// Searches the board for the title and ranks the results by how closely
+    /// their titles match it: the better of shared-word overlap and edit
+    /// distance, from 0 (nothing in common) to 1 (identical). Posts below
+    /// --threshold are left out.
+    ///
+    /// EXAMPLES:
+    ///   canny posts find-similar --board-id abc123 --title "Dark mode for the dashboard"
+    ///   canny posts find-similar --board-id abc123 --title "Export to CSV" --threshold 0.6
+    ///   canny posts find-similar --board-id abc123 --title "SSO login" --json
+    FindSimilar {
+        /// The ID of the board to search
+        #[arg(long)]
+        board_id: String,
+
+        /// Title of the post you're about to create
+        #[arg(long)]
+        title: String,
+
+        /// Minimum similarity from 0 to 1 for a post to be shown
+        #[arg(long, default_value = "0.4", value_parser = parse_similarity)]
+        threshold: f64,
+
+        /// Maximum number of search results to compare
+        #[arg(long, default_value = "50")]
+        limit: u32,
+    },
+
+    /// Merge a duplicate post into another
+    ///
+    /// Folds the post given by --id into the post given by --merge-into. The
+    /// duplicate's votes and comments move to the surviving post, and the
+    /// duplicate stops being listed. This can't be undone from the CLI.
+    ///
+    /// EXAMPLES:
+    ///   canny posts merge --id dup123 --merge-into post456
+    ///   canny posts merge --id dup123 --merge-into post456 --yes
+    Merge {
+        /// The ID of the duplicate post
         #[arg(long)]
         id: String,
+
+        /// The ID of the post to keep
+        #[arg(long)]
+        merge_into: String,
+
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
     },
 
     /// Add a tag to a post
@@ -508,6 +1207,9 @@ enum CommentsCommands {
     ///
     ///   # List with pagination
     ///   canny comments list --post-id post123 --limit 50 --skip 100
+    ///
+    ///   # Only public comments, safe to share outside the team
+    ///   canny comments list --board-id board789 --public --json
     List {
         /// The ID of the post to list comments from (optional)
         #[arg(long)]
@@ -532,11 +1234,22 @@ enum CommentsCommands {
         /// Number of comments to skip (for pagination)
         #[arg(long, default_value = "0")]
         skip: u32,
+
+        /// Only show internal (admin-only) comments; filtered after fetching,
+        /// so a page may hold fewer than --limit
+        #[arg(long, conflicts_with = "public")]
+        internal: bool,
+
+        /// Only show public comments; filtered after fetching, so a page may
+        /// hold fewer than --limit
+        #[arg(long)]
+        public: bool,
     },
 
     /// Create a comment on a post
     ///
     /// Adds a new comment to a post. Can optionally be a reply to another comment.
+    /// The author is given by ID or looked up by email.
     ///
     /// EXAMPLES:
     ///   # Add a top-level comment
@@ -554,25 +1267,41 @@ enum CommentsCommands {
     ///   # Create a comment with images
     ///   canny comments create --post-id post123 --author-id user456 \
     ///     --value "See attached" --image-url "https://example.com/img1.png"
+    ///
+    ///   # Reply and email the post's voters
+    ///   canny comments create --post-id post123 --author-id user456 \
+    ///     --value "Shipped in 2.4" --notify
+    ///
+    ///   # Comment as a user known only by email
+    ///   canny comments create --post-id post123 --author-email jane@example.com \
+    ///     --value "Thanks, we're on it"
+    ///
+    ///   # Send the whole request body from a file
+    ///   canny comments create --input-json @comment.json
     Create {
         /// The ID of the post to comment on
-        #[arg(long)]
-        post_id: String,
+        #[arg(long, required_unless_present = "input_json")]
+        post_id: Option<String>,
 
         /// The ID of the user creating the comment
-        #[arg(long)]
-        author_id: String,
+        #[arg(long, required_unless_present_any = ["author_email", "input_json"])]
+        author_id: Option<String>,
 
-        /// The comment text (supports markdown)
+        /// Email of the user creating the comment, looked up to find their ID
+        /// (--author-id takes precedence)
         #[arg(long)]
-        value: String,
+        author_email: Option<String>,
+
+        /// The comment text (supports markdown; @FILE reads a file, - reads stdin)
+        #[arg(long, required_unless_present = "input_json")]
+        value: Option<String>,
 
         /// Parent comment ID if this is a reply
         #[arg(long)]
         parent_id: Option<String>,
 
         /// Creation timestamp (ISO 8601 format)
-        #[arg(long)]
+        #[arg(long, value_parser = parse_iso8601)]
         created_at: Option<String>,
 
         /// Image URLs to attach to the comment (can be specified multiple times)
@@ -583,10 +1312,23 @@ enum CommentsCommands {
         #[arg(long)]
         internal: bool,
 
-        /// Notify voters about this comment
-        #[arg(long)]
-        notify_voters: bool,
-    },
+        /// Notify the post's voters about this comment
+        #[arg(long, visible_alias = "notify-voters")]
+        notify: bool,
+
+        /// The whole request body as a JSON object instead of the flags above
+        /// (@FILE reads a file, - reads stdin); must include postID, authorID
+        /// and value
+        #[arg(
+            long,
+            value_name = "JSON",
+            conflicts_with_all = [
+                "post_id", "author_id", "author_email", "value", "parent_id", "created_at",
+                "image_urls", "internal", "notify",
+            ]
+        )]
+        input_json: Option<String>,
+    },
 
     /// Retrieve a single comment by ID
     ///
@@ -601,6 +1343,30 @@ enum CommentsCommands {
         id: String,
     },
 
+    /// Update a comment
+    ///
+    /// Replaces the text (and optionally the images) of an existing comment.
+    ///
+    /// EXAMPLES:
+    ///   # Fix a typo
+    ///   canny comments update --id comment123 --value "Corrected text"
+    ///
+    ///   # Replace the text with the contents of a file
+    ///   canny comments update --id comment123 --value @reply.md
+    Update {
+        /// The ID of the comment to update
+        #[arg(long)]
+        id: String,
+
+        /// New comment text (supports markdown; @FILE reads a file, - reads stdin)
+        #[arg(long)]
+        value: String,
+
+        /// Image URLs to attach, replacing existing ones (can be specified multiple times)
+        #[arg(long = "image-url")]
+        image_urls: Vec<String>,
+    },
+
     /// Delete a comment
     ///
     /// Permanently deletes a comment by ID.
@@ -677,6 +1443,30 @@ enum CategoriesCommands {
         subscribe_admins: bool,
     },
 
+    /// Update a category
+    ///
+    /// Renames a category or moves it under a different parent. The category
+    /// keeps its ID, so posts stay associated with it.
+    ///
+    /// EXAMPLES:
+    ///   canny categories update --id cat123 --name "Integrations"
+    ///
+    ///   # Move a category under another one
+    ///   canny categories update --id cat123 --parent-id cat456
+    Update {
+        /// The ID of the category to update
+        #[arg(long)]
+        id: String,
+
+        /// New name for the category
+        #[arg(long)]
+        name: Option<String>,
+
+        /// ID of the new parent category
+        #[arg(long)]
+        parent_id: Option<String>,
+    },
+
     /// Delete a category
     ///
     /// Permanently deletes a category by ID.
@@ -695,12 +1485,38 @@ enum UsersCommands {
     /// List all users
     ///
     /// Retrieves all users in your Canny account (automatically fetches all pages).
+    /// Filters are applied by the API, so narrowing the list also makes it faster.
     /// Use this to find user IDs for creating posts and comments.
     ///
     /// EXAMPLES:
     ///   canny users list
     ///   canny users list --json
-    List,
+    ///
+    ///   # Only users at one company, matching a name or email
+    ///   canny users list --company-id company123 --search alice
+    ///
+    ///   # Quick sample of 20 users
+    ///   canny users list --limit 20
+    ///
+    ///   # Count a company's users
+    ///   canny users list --company-id company123 --count-only
+    List {
+        /// Only list users belonging to this company
+        #[arg(long)]
+        company_id: Option<String>,
+
+        /// Only list users whose name or email matches this text
+        #[arg(long)]
+        search: Option<String>,
+
+        /// Stop after this many users (default: fetch all)
+        #[arg(long)]
+        limit: Option<usize>,
+
+        /// Print only the number of users found instead of listing them
+        #[arg(long)]
+        count_only: bool,
+    },
 
     /// Retrieve a user by ID or email
     ///
@@ -738,14 +1554,28 @@ enum UsersCommands {
     ///   # Create a user with custom fields
     ///   canny users create --user-id user123 --email user@example.com --name "John Doe" \
     ///     --custom-fields '{"plan": "enterprise", "role": "admin"}'
+    ///
+    ///   # Create or update every user in an NDJSON file, 10 at a time
+    ///   canny users create --from-file users.ndjson --concurrency 10
+    ///
+    ///   # Send the whole request body from a file
+    ///   canny users create --input-json @user.json
     Create {
         /// Unique identifier for the user in your system
-        #[arg(long)]
-        user_id: String,
+        #[arg(
+            long,
+            required_unless_present_any = ["from_file", "input_json"],
+            conflicts_with_all = ["from_file", "input_json"]
+        )]
+        user_id: Option<String>,
 
         /// Email address of the user (required)
-        #[arg(long)]
-        email: String,
+        #[arg(
+            long,
+            required_unless_present_any = ["from_file", "input_json"],
+            conflicts_with_all = ["from_file", "input_json"]
+        )]
+        email: Option<String>,
 
         /// Internal Canny ID (different from userID, used for updating existing users)
         #[arg(long)]
@@ -766,6 +1596,31 @@ enum UsersCommands {
         /// Custom fields as a JSON string (e.g., '{"plan": "enterprise"}')
         #[arg(long)]
         custom_fields: Option<String>,
+
+        /// Create or update users from an NDJSON file, one object per line with
+        /// userID, email, and optionally name, avatarURL, companyID, customFields.
+        /// Each user is attempted even if earlier ones fail.
+        #[arg(
+            long,
+            value_name = "PATH",
+            conflicts_with_all = ["id", "name", "avatar_url", "company_id", "custom_fields"]
+        )]
+        from_file: Option<PathBuf>,
+
+        /// Maximum number of users created or updated at once with --from-file
+        #[arg(long, default_value = "5", value_parser = clap::value_parser!(u32).range(1..))]
+        concurrency: u32,
+
+        /// The whole request body as a JSON object instead of the flags above
+        /// (@FILE reads a file, - reads stdin); must include userID and email
+        #[arg(
+            long,
+            value_name = "JSON",
+            conflicts_with_all = [
+                "from_file", "id", "name", "avatar_url", "company_id", "custom_fields",
+            ]
+        )]
+        input_json: Option<String>,
     },
 
     /// Delete a user
@@ -808,6 +1663,23 @@ enum UsersCommands {
         name: Option<String>,
     },
 
+    /// Add a user to a company
+    ///
+    /// Associates a user with a company, creating the company if it doesn't
+    /// exist yet.
+    ///
+    /// EXAMPLES:
+    ///   canny users add-to-company --user-id user123 --company-id company456
+    AddToCompany {
+        /// The ID of the user to add
+        #[arg(long)]
+        user_id: String,
+
+        /// The ID of the company to add the user to
+        #[arg(long)]
+        company_id: String,
+    },
+
     /// Remove a user from a company
     ///
     /// Removes the association between a user and a company.
@@ -825,6 +1697,34 @@ enum UsersCommands {
     },
 }
 
+#[derive(Subcommand)]
+enum SegmentsCommands {
+    /// List all segments
+    ///
+    /// Shows each segment's ID, name and URL name. The URL name is what
+    /// `companies list --segment` expects.
+    ///
+    /// EXAMPLES:
+    ///   canny segments list
+    ///   canny segments list --json
+    List,
+
+    /// Retrieve a single segment by ID or URL name
+    ///
+    /// EXAMPLES:
+    ///   canny segments get --id seg123
+    ///   canny segments get --url-name enterprise-customers
+    Get {
+        /// The ID of the segment
+        #[arg(long, required_unless_present = "url_name", conflicts_with = "url_name")]
+        id: Option<String>,
+
+        /// The URL name of the segment
+        #[arg(long)]
+        url_name: Option<String>,
+    },
+}
+
 #[derive(Subcommand)]
 enum BoardsCommands {
     /// List all boards
@@ -839,14 +1739,21 @@ enum BoardsCommands {
 
     /// Retrieve a single board by ID
     ///
-    /// Gets detailed information about a specific board.
+    /// Gets detailed information about a specific board. The board's token,
+    /// used to identify users in the widget, is hidden unless --show-token
+    /// is given.
     ///
     /// EXAMPLES:
     ///   canny boards get --id board123
+    ///   canny boards get --id board123 --show-token
     Get {
         /// The ID of the board to retrieve
         #[arg(long)]
         id: String,
+
+        /// Show the board's token in the details
+        #[arg(long)]
+        show_token: bool,
     },
 
     /// Create a new board
@@ -861,6 +1768,32 @@ enum BoardsCommands {
         name: String,
     },
 
+    /// Update a board
+    ///
+    /// Renames a board or changes its privacy settings. Only the provided
+    /// fields are changed.
+    ///
+    /// EXAMPLES:
+    ///   canny boards update --id board123 --name "Integrations"
+    ///   canny boards update --id board123 --is-private true --private-comments false
+    Update {
+        /// The ID of the board to update
+        #[arg(long)]
+        id: String,
+
+        /// New name for the board
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Whether the board is only visible to admins and invited users
+        #[arg(long)]
+        is_private: Option<bool>,
+
+        /// Whether comments on the board are only visible to admins
+        #[arg(long)]
+        private_comments: Option<bool>,
+    },
+
     /// Delete a board
     ///
     /// Permanently deletes a board by ID.
@@ -882,6 +1815,9 @@ enum TagsCommands {
     ///
     /// EXAMPLES:
     ///   canny tags list --board-id abc123
+    ///
+    ///   # Fetch every tag on the board
+    ///   canny tags list --board-id abc123 --all
     List {
         /// The ID of the board to list tags from
         #[arg(long)]
@@ -894,6 +1830,28 @@ enum TagsCommands {
         /// Number of tags to skip (for pagination)
         #[arg(long, default_value = "0")]
         skip: u32,
+
+        /// Fetch all pages (ignores --limit and --skip)
+        #[arg(long)]
+        all: bool,
+
+        /// Print only the number of tags found instead of listing them
+        /// (requires --all)
+        #[arg(long, requires = "all")]
+        count_only: bool,
+    },
+
+    /// Report how much each tag on a board is used
+    ///
+    /// Lists every tag on the board from least to most used, flagging tags
+    /// with no posts as candidates for deletion.
+    ///
+    /// EXAMPLES:
+    ///   canny tags report --board-id abc123
+    Report {
+        /// The ID of the board to report on
+        #[arg(long)]
+        board_id: String,
     },
 
     /// Retrieve a single tag by ID
@@ -924,6 +1882,26 @@ enum TagsCommands {
         name: String,
     },
 
+    /// Rename a tag
+    ///
+    /// Changes a tag's name; posts keep the tag.
+    ///
+    /// EXAMPLES:
+    ///   canny tags update --id tag123 --name "bug"
+    Update {
+        /// The ID of the tag to rename
+        #[arg(long)]
+        id: String,
+
+        /// New name for the tag
+        #[arg(long)]
+        name: String,
+
+        /// The board the tag belongs to, for accounts where tag changes are board-scoped
+        #[arg(long)]
+        board_id: Option<String>,
+    },
+
     /// Delete a tag
     ///
     /// Permanently deletes a tag by ID.
@@ -949,6 +1927,12 @@ enum CompaniesCommands {
     ///   canny companies list --limit 50
     ///   canny companies list --search "Acme"
     ///   canny companies list --segment enterprise-customers
+    ///
+    ///   # Top-spending companies across the whole account
+    ///   canny companies list --all --sort spend --min-monthly-spend 1000
+    ///
+    ///   # Fetch only what's new since the last scheduled run
+    ///   canny companies list --all --cursor-file companies.cursor --format ndjson
     List {
         /// Maximum number of companies to return (default: 100)
         #[arg(long, default_value = "100")]
@@ -962,9 +1946,31 @@ enum CompaniesCommands {
         #[arg(long)]
         search: Option<String>,
 
-        /// Filter by segment URL name
+        /// Filter by segment URL name (see `canny segments list`)
         #[arg(long)]
         segment: Option<String>,
+
+        /// Only show companies spending at least this much per month
+        #[arg(long)]
+        min_monthly_spend: Option<f64>,
+
+        /// Sort the returned companies
+        #[arg(long, value_enum)]
+        sort: Option<CompanySort>,
+
+        /// Fetch all pages (ignores --limit and --cursor)
+        #[arg(long, conflicts_with = "cursor")]
+        all: bool,
+
+        /// Print only the number of companies found instead of listing them
+        /// (requires --all)
+        #[arg(long, requires = "all")]
+        count_only: bool,
+
+        /// Start from the cursor saved in this file, if it exists, and save
+        /// the cursor to resume from afterwards (for incremental syncs)
+        #[arg(long, value_name = "PATH", conflicts_with = "cursor")]
+        cursor_file: Option<PathBuf>,
     },
 
     /// Retrieve a single company by ID
@@ -979,6 +1985,40 @@ enum CompaniesCommands {
         id: String,
     },
 
+    /// Create a company
+    ///
+    /// Creates a company under your own company ID by adding a user to it;
+    /// Canny only creates companies through their users.
+    ///
+    /// EXAMPLES:
+    ///   canny companies create --id acme --name "Acme Corp" --user-id user123
+    ///   canny companies create --id acme --name "Acme Corp" --user-id user123 --monthly-spend 5000 --custom-fields '{"tier": "enterprise"}'
+    Create {
+        /// Your ID for the company
+        #[arg(long)]
+        id: String,
+
+        /// Your ID for a user to add to the company
+        #[arg(long)]
+        user_id: String,
+
+        /// Name of the company
+        #[arg(long)]
+        name: String,
+
+        /// Monthly spend amount for the company
+        #[arg(long)]
+        monthly_spend: Option<f64>,
+
+        /// Custom fields as JSON object
+        #[arg(long)]
+        custom_fields: Option<String>,
+
+        /// Company creation date (ISO 8601 format)
+        #[arg(long, value_parser = parse_iso8601)]
+        created: Option<String>,
+    },
+
     /// Update a company
     ///
     /// Updates company information including name, monthly spend, custom fields, and creation date.
@@ -1013,7 +2053,7 @@ enum CompaniesCommands {
         custom_fields: Option<String>,
 
         /// Company creation date (ISO 8601 format)
-        #[arg(long)]
+        #[arg(long, value_parser = parse_iso8601)]
         created: Option<String>,
     },
 
@@ -1082,6 +2122,9 @@ enum VotesCommands {
     ///
     /// EXAMPLES:
     ///   canny votes create --post-id post123 --user-id user456
+    ///
+    ///   # Backfill a historical vote during a migration
+    ///   canny votes create --post-id post123 --user-id user456 --created-at 2021-03-04T10:00:00Z
     Create {
         /// The ID of the post to vote on
         #[arg(long)]
@@ -1090,6 +2133,14 @@ enum VotesCommands {
         /// The ID of the user voting
         #[arg(long)]
         user_id: String,
+
+        /// The admin user ID casting the vote on the user's behalf
+        #[arg(long)]
+        by_id: Option<String>,
+
+        /// Vote timestamp (ISO 8601 format, for imports)
+        #[arg(long, value_parser = parse_iso8601)]
+        created_at: Option<String>,
     },
 
     /// Delete a vote
@@ -1117,6 +2168,9 @@ enum StatusChangesCommands {
     ///
     ///   # List with pagination
     ///   canny status-changes list --board-id abc123 --limit 50 --skip 100
+    ///
+    ///   # Every status change, with the title of the post that changed
+    ///   canny status-changes list --board-id abc123 --all --resolve-posts
     List {
         /// The ID of the board to list status changes from
         #[arg(long)]
@@ -1129,6 +2183,24 @@ enum StatusChangesCommands {
         /// Number of status changes to skip (for pagination)
         #[arg(long, default_value = "0")]
         skip: u32,
+
+        /// Fetch all pages (ignores --limit and --skip)
+        #[arg(long)]
+        all: bool,
+
+        /// Print only the number of status changes found instead of listing them
+        /// (requires --all)
+        #[arg(long, requires = "all", conflicts_with = "resolve_posts")]
+        count_only: bool,
+
+        /// Fetch each referenced post once to show its title (under
+        /// "postTitle" in JSON output)
+        #[arg(long)]
+        resolve_posts: bool,
+
+        /// Maximum number of posts fetched at once with --resolve-posts
+        #[arg(long, default_value = "4", value_parser = clap::value_parser!(u32).range(1..))]
+        concurrency: u32,
     },
 }
 
@@ -1144,6 +2216,9 @@ enum OpportunitiesCommands {
     ///
     ///   # List with pagination
     ///   canny opportunities list --post-id post123 --limit 50 --skip 100
+    ///
+    ///   # Biggest deals first, with the pipeline total
+    ///   canny opportunities list --post-id post123 --limit 100 --sort value
     List {
         /// The ID of the post to list opportunities from
         #[arg(long)]
@@ -1156,6 +2231,10 @@ enum OpportunitiesCommands {
         /// Number of opportunities to skip (for pagination)
         #[arg(long, default_value = "0")]
         skip: u32,
+
+        /// Sort the returned opportunities
+        #[arg(long, value_enum)]
+        sort: Option<OpportunitySort>,
     },
 }
 
@@ -1213,12 +2292,18 @@ enum ChangelogCommands {
     ///
     ///   # Create with linked posts
     ///   canny changelog create --title "New Feature" --post-id post123 --post-id post456
+    ///
+    ///   # Read the details from a file (or pipe them in with --details -)
+    ///   canny changelog create --title "v2.3 Release" --details @release-notes.md
+    ///
+    ///   # Send the whole request body from a file
+    ///   canny changelog create --input-json @entry.json
     Create {
         /// Title of the changelog entry
-        #[arg(long)]
-        title: String,
+        #[arg(long, required_unless_present = "input_json")]
+        title: Option<String>,
 
-        /// Detailed description (supports markdown)
+        /// Detailed description (supports markdown; @FILE reads a file, - reads stdin)
         #[arg(long)]
         details: Option<String>,
 
@@ -1228,11 +2313,11 @@ enum ChangelogCommands {
 
         /// Publish the entry immediately
         #[arg(long)]
-        published: Option<bool>,
+        published: bool,
 
         /// Notify users about this entry
         #[arg(long)]
-        notify: Option<bool>,
+        notify: bool,
 
         /// Post IDs to link to this entry (can be specified multiple times)
         #[arg(long = "post-id")]
@@ -1243,12 +2328,24 @@ enum ChangelogCommands {
         label_ids: Vec<String>,
 
         /// ISO 8601 date for past publication (e.g., "2024-01-15T10:00:00Z")
-        #[arg(long)]
+        #[arg(long, value_parser = parse_iso8601)]
         published_on: Option<String>,
 
         /// ISO 8601 date for future scheduled publication (e.g., "2024-02-01T10:00:00Z")
-        #[arg(long)]
+        #[arg(long, value_parser = parse_iso8601)]
         scheduled_for: Option<String>,
+
+        /// The whole request body as a JSON object instead of the flags above
+        /// (@FILE reads a file, - reads stdin); must include title
+        #[arg(
+            long,
+            value_name = "JSON",
+            conflicts_with_all = [
+                "title", "details", "type", "published", "notify", "post_ids", "label_ids",
+                "published_on", "scheduled_for",
+            ]
+        )]
+        input_json: Option<String>,
     },
 
     /// Retrieve a single changelog entry by ID
@@ -1290,7 +2387,7 @@ enum ChangelogCommands {
     ///   canny changelog update --id entry123 --published true
     ///
     ///   # Publish and notify users
-    ///   canny changelog update --id entry123 --published true --notify true
+    ///   canny changelog update --id entry123 --published true --notify
     Update {
         /// The ID of the changelog entry to update
         #[arg(long)]
@@ -1312,14 +2409,40 @@ enum ChangelogCommands {
         #[arg(long)]
         published: Option<bool>,
 
-        /// Whether to notify users about this entry
+        /// Notify users about this entry
         #[arg(long)]
-        notify: Option<bool>,
+        notify: bool,
 
         /// Label IDs to assign to this entry (can be specified multiple times)
         #[arg(long = "label-id")]
         label_ids: Vec<String>,
     },
+
+    /// Manage changelog labels
+    ///
+    /// Labels categorize changelog entries. Use these to find the IDs that
+    /// `changelog create --label-id` expects.
+    #[command(subcommand)]
+    Labels(ChangelogLabelsCommands),
+}
+
+#[derive(Subcommand)]
+enum ChangelogLabelsCommands {
+    /// List changelog labels
+    ///
+    /// EXAMPLES:
+    ///   canny changelog labels list
+    List,
+
+    /// Create a changelog label
+    ///
+    /// EXAMPLES:
+    ///   canny changelog labels create --name "Integrations"
+    Create {
+        /// Name of the label
+        #[arg(long)]
+        name: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -1332,6 +2455,7 @@ enum GroupsCommands {
     ///   canny groups list
     ///   canny groups list --limit 50
     ///   canny groups list --cursor abc123
+    ///   canny groups list --all --json
     List {
         /// Maximum number of groups to return (default: 10, max: 10000)
         #[arg(long, default_value = "10")]
@@ -1340,6 +2464,20 @@ enum GroupsCommands {
         /// Cursor for pagination (from previous response)
         #[arg(long)]
         cursor: Option<String>,
+
+        /// Fetch all pages (ignores --limit and --cursor)
+        #[arg(long, conflicts_with = "cursor")]
+        all: bool,
+
+        /// Print only the number of groups found instead of listing them
+        /// (requires --all)
+        #[arg(long, requires = "all")]
+        count_only: bool,
+
+        /// Start from the cursor saved in this file, if it exists, and save
+        /// the cursor to resume from afterwards (for incremental syncs)
+        #[arg(long, value_name = "PATH", conflicts_with = "cursor")]
+        cursor_file: Option<PathBuf>,
     },
 
     /// Retrieve a single group by ID or URL name
@@ -1373,6 +2511,7 @@ enum InsightsCommands {
     ///   canny insights list
     ///   canny insights list --limit 50
     ///   canny insights list --idea-id idea123
+    ///   canny insights list --all --json
     List {
         /// Maximum number of insights to return (default: 10, max: 10000)
         #[arg(long, default_value = "10")]
@@ -1385,6 +2524,20 @@ enum InsightsCommands {
         /// Filter insights by idea ID
         #[arg(long)]
         idea_id: Option<String>,
+
+        /// Fetch all pages (ignores --limit and --cursor)
+        #[arg(long, conflicts_with = "cursor")]
+        all: bool,
+
+        /// Print only the number of insights found instead of listing them
+        /// (requires --all)
+        #[arg(long, requires = "all")]
+        count_only: bool,
+
+        /// Start from the cursor saved in this file, if it exists, and save
+        /// the cursor to resume from afterwards (for incremental syncs)
+        #[arg(long, value_name = "PATH", conflicts_with = "cursor")]
+        cursor_file: Option<PathBuf>,
     },
 
     /// Retrieve a single insight by ID
@@ -1411,6 +2564,7 @@ enum IdeasCommands {
     ///   canny ideas list --limit 50
     ///   canny ideas list --parent-id parent123
     ///   canny ideas list --search "feature"
+    ///   canny ideas list --all --json
     List {
         /// Maximum number of ideas to return (default: 10, max: 10000)
         #[arg(long, default_value = "10")]
@@ -1427,6 +2581,20 @@ enum IdeasCommands {
         /// Search term to filter ideas
         #[arg(long)]
         search: Option<String>,
+
+        /// Fetch all pages (ignores --limit and --cursor)
+        #[arg(long, conflicts_with = "cursor")]
+        all: bool,
+
+        /// Print only the number of ideas found instead of listing them
+        /// (requires --all)
+        #[arg(long, requires = "all")]
+        count_only: bool,
+
+        /// Start from the cursor saved in this file, if it exists, and save
+        /// the cursor to resume from afterwards (for incremental syncs)
+        #[arg(long, value_name = "PATH", conflicts_with = "cursor")]
+        cursor_file: Option<PathBuf>,
     },
 
     /// Retrieve a single idea by ID or URL name
@@ -1477,82 +2645,287 @@ enum AutopilotCommands {
     },
 }
 
-#[tokio::main]
-async fn main() -> Result<()> {
-    let cli = Cli::parse();
+/// Returned by bulk commands when some items failed. Each failure has
+/// already been reported, so it only sets the exit code.
+#[derive(Debug)]
+struct PartialFailure;
+
+impl std::fmt::Display for PartialFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "some items failed")
+    }
+}
+
+impl std::error::Error for PartialFailure {}
+
+/// Returned when the object a command asked for doesn't exist, naming what
+/// was looked up; it exits with `EXIT_NOT_FOUND`
+#[derive(Debug)]
+struct NotFound(&'static str);
+
+impl std::fmt::Display for NotFound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} not found.", self.0)
+    }
+}
+
+impl std::error::Error for NotFound {}
+
+/// Returned when a listing printed what it fetched before a page failed for
+/// good. The failure was already reported, so it only sets the exit code.
+#[derive(Debug)]
+struct IncompleteResults;
+
+impl std::fmt::Display for IncompleteResults {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "results are incomplete")
+    }
+}
+
+impl std::error::Error for IncompleteResults {}
+
+fn main() {
+    // The env file is loaded before the runtime starts its worker threads,
+    // since changing the environment isn't safe while other threads read it
+    let (env_file, api_key_flag) = config::env_file_args(std::env::args_os());
+    let result = config::load_env_file(env_file.as_deref(), api_key_flag).and_then(|()| {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .context("Failed to start the async runtime")?
+            .block_on(run())
+    });
+    if let Err(e) = result {
+        if e.is::<PartialFailure>() {
+            std::process::exit(EXIT_FAILURE);
+        }
+        if e.is::<IncompleteResults>() {
+            std::process::exit(EXIT_INCOMPLETE);
+        }
+        if e.is::<NotFound>() {
+            eprintln!("{}", e.to_string().red());
+            std::process::exit(EXIT_NOT_FOUND);
+        }
+        eprintln!("Error: {:?}", e);
+        let code = e
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<CannyApiError>())
+            .map_or(EXIT_FAILURE, CannyApiError::exit_code);
+        std::process::exit(code);
+    }
+}
+
+async fn run() -> Result<()> {
+    // Defaults from the config file fill in flags that weren't given
+    let matches = config::apply_defaults(Cli::command(), &config::load_defaults()).get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    output::configure_color(if cli.no_color { ColorChoice::Never } else { cli.color });
+    output::configure_markdown(cli.markdown);
+    // Templates render the same serialized items as JSON list output
+    let format = if cli.json || cli.template.is_some() {
+        OutputFormat::Json
+    } else {
+        cli.format
+    };
+    let json_output = format.is_json();
+    output::set_quiet(cli.quiet);
+    output::set_compact(cli.compact);
+    output::set_json_lines(format == OutputFormat::Ndjson);
+    output::set_json_fields(cli.fields.clone());
+    if let Some(ref template) = cli.template {
+        output::set_template(template.clone())?;
+    }
+
+    if !supports_format(format, &cli.command) {
+        anyhow::bail!("--format {} is not supported by this command", format);
+    }
+
+    let http_options = HttpOptions {
+        timeout: (cli.timeout > 0).then(|| Duration::from_secs(cli.timeout)),
+        proxy: cli.proxy.clone(),
+        ca_bundle: cli.ca_bundle.clone(),
+        user_agent: cli.user_agent.clone(),
+        insecure: cli.insecure,
+    };
+
+    if let Commands::Version = cli.command {
+        return handle_version(json_output);
+    }
 
     // Handle auth before credential resolution
-    if let Commands::Auth { reset } = &cli.command {
+    if let Commands::Auth { reset, command } = &cli.command {
+        match command {
+            Some(AuthCommands::List) => return handle_auth_list(&cli.profile, json_output),
+            Some(AuthCommands::Export { show_secret }) => {
+                return handle_auth_export(&cli.profile, *show_secret, json_output);
+            }
+            Some(AuthCommands::Import) => {
+                return handle_auth_import(
+                    cli.api_key,
+                    cli.api_key_file.as_deref(),
+                    cli.api_url,
+                    &cli.profile,
+                    json_output,
+                );
+            }
+            None => {}
+        }
         if *reset {
-            let _ = credentials::clear_stored_credentials();
+            let _ = credentials::clear_stored_credentials(&cli.profile);
             println!("  {} Credentials cleared.", "✓".green().bold());
             println!();
         }
-        return handle_auth(cli.api_key, cli.api_url).await;
+        return handle_auth(
+            cli.api_key,
+            cli.api_key_file.as_deref(),
+            cli.api_url,
+            &cli.profile,
+            &http_options,
+        )
+        .await;
     }
 
-    // Resolve API key: 1) flag/env var, 2) Keychain
-    let api_key = credentials::resolve_api_key(cli.api_key)?;
+    // Resolve API key: 1) flag/env var, 2) key file, 3) credential store, 4) config file
+    let api_key =
+        credentials::resolve_api_key(cli.api_key, cli.api_key_file.as_deref(), &cli.profile)?;
 
-    // Resolve API URL: 1) --api-url flag, 2) Keychain, 3) default
+    // Resolve API URL: 1) --api-url flag, 2) credential store, 3) config file, 4) default
     let api_url = cli
         .api_url
-        .or_else(|| credentials::resolve_api_url(None, DEFAULT_API_URL))
+        .or_else(|| credentials::resolve_api_url(None, DEFAULT_API_URL, &cli.profile))
         .unwrap_or_else(|| DEFAULT_API_URL.to_string());
 
-    let client = CannyClient::new(api_url, api_key);
+    let masked_key = credentials::mask_api_key(&api_key);
+    let client = CannyClient::with_http_options(api_url.clone(), api_key, &http_options)?
+        .with_max_retries(cli.max_retries)
+        .with_page_size(cli.page_size)
+        .with_verbose(cli.verbose)
+        .with_dry_run(cli.dry_run)
+        .with_raw(cli.raw)
+        .with_api_version(cli.api_version)
+        .with_deadline(cli.deadline.map(Duration::from_secs))
+        .with_skip_invalid(cli.skip_invalid)
+        .with_audit_log(cli.audit_log.as_deref(), cli.audit_all)?;
+
+    let result = match cli.command {
+        Commands::Posts(cmd) => handle_posts(&client, cmd, format).await,
+        Commands::Comments(cmd) => handle_comments(&client, cmd, json_output).await,
+        Commands::Categories(cmd) => handle_categories(&client, cmd, json_output).await,
+        Commands::Users(cmd) => handle_users(&client, cmd, format).await,
+        Commands::Boards(cmd) => handle_boards(&client, cmd, format).await,
+        Commands::Tags(cmd) => handle_tags(&client, cmd, format).await,
+        Commands::Companies(cmd) => handle_companies(&client, cmd, format).await,
+        Commands::Segments(cmd) => handle_segments(&client, cmd, json_output).await,
+        Commands::Votes(cmd) => handle_votes(&client, cmd, json_output).await,
+        Commands::StatusChanges(cmd) => handle_status_changes(&client, cmd, json_output).await,
+        Commands::Changelog(cmd) => handle_changelog(&client, cmd, json_output).await,
+        Commands::Opportunities(cmd) => handle_opportunities(&client, cmd, json_output).await,
+        Commands::Groups(cmd) => handle_groups(&client, cmd, format).await,
+        Commands::Insights(cmd) => handle_insights(&client, cmd, format).await,
+        Commands::Ideas(cmd) => handle_ideas(&client, cmd, format).await,
+        Commands::Autopilot(cmd) => handle_autopilot(&client, cmd, json_output).await,
+        Commands::Export {
+            board_id,
+            out,
+            include_comments,
+            include_votes,
+        } => {
+            handle_export(&client, &board_id, &out, include_comments, include_votes, json_output)
+                .await
+        }
+        Commands::Api { path, data } => handle_api(&client, &path, data.as_deref()).await,
+        Commands::Whoami => {
+            // Only whoami reports where the key came from, which may mean
+            // asking the credential store again
+            let key_source = match matches.value_source("api_key") {
+                Some(ValueSource::CommandLine) => "--api-key flag".to_string(),
+                Some(ValueSource::EnvVariable) => "CANNY_API_KEY environment variable".to_string(),
+                _ => match cli.api_key_file {
+                    Some(ref path) => format!("key file ({})", path.display()),
+                    None => credentials::stored_api_key_backend(&cli.profile)
+                        .map(|backend| backend.to_string())
+                        .unwrap_or_else(|| "unknown".to_string()),
+                },
+            };
+            handle_whoami(&client, &cli.profile, &api_url, &masked_key, &key_source, json_output)
+                .await
+        }
+        Commands::Auth { .. } | Commands::Version => unreachable!(),
+    };
+
+    if cli.stats {
+        client.print_stats();
+    }
+
+    let skipped = client.skipped_count();
+    if skipped > 0 {
+        eprintln!(
+            "{} skipped {} invalid item{} (--skip-invalid)",
+            "warning:".yellow(),
+            skipped,
+            if skipped == 1 { "" } else { "s" }
+        );
+    }
+
+    // --raw stops at the first request it prints
+    match result {
+        Err(e) if e.is::<RawPrinted>() => Ok(()),
+        Ok(()) if client.results_incomplete() => Err(IncompleteResults.into()),
+        other => other,
+    }
+}
 
-    match cli.command {
-        Commands::Posts(cmd) => handle_posts(&client, cmd, cli.json).await,
-        Commands::Comments(cmd) => handle_comments(&client, cmd, cli.json).await,
-        Commands::Categories(cmd) => handle_categories(&client, cmd, cli.json).await,
-        Commands::Users(cmd) => handle_users(&client, cmd, cli.json).await,
-        Commands::Boards(cmd) => handle_boards(&client, cmd, cli.json).await,
-        Commands::Tags(cmd) => handle_tags(&client, cmd, cli.json).await,
-        Commands::Companies(cmd) => handle_companies(&client, cmd, cli.json).await,
-        Commands::Votes(cmd) => handle_votes(&client, cmd, cli.json).await,
-        Commands::StatusChanges(cmd) => handle_status_changes(&client, cmd, cli.json).await,
-        Commands::Changelog(cmd) => handle_changelog(&client, cmd, cli.json).await,
-        Commands::Opportunities(cmd) => handle_opportunities(&client, cmd, cli.json).await,
-        Commands::Groups(cmd) => handle_groups(&client, cmd, cli.json).await,
-        Commands::Insights(cmd) => handle_insights(&client, cmd, cli.json).await,
-        Commands::Ideas(cmd) => handle_ideas(&client, cmd, cli.json).await,
-        Commands::Autopilot(cmd) => handle_autopilot(&client, cmd, cli.json).await,
-        Commands::Auth { .. } => unreachable!(),
+/// Whether a command can render its output in the given format
+fn supports_format(format: OutputFormat, command: &Commands) -> bool {
+    match format {
+        OutputFormat::Text | OutputFormat::Json | OutputFormat::Ndjson => true,
+        OutputFormat::Csv => matches!(
+            command,
+            Commands::Posts(PostsCommands::List { .. })
+                | Commands::Companies(CompaniesCommands::List { .. })
+        ),
+        OutputFormat::Table => matches!(
+            command,
+            Commands::Posts(PostsCommands::List { .. })
+                | Commands::Boards(BoardsCommands::List)
+                | Commands::Companies(CompaniesCommands::List { .. })
+                | Commands::Tags(TagsCommands::List { .. })
+                | Commands::Users(UsersCommands::List { .. })
+        ),
     }
 }
 
 async fn handle_auth(
     explicit_key: Option<String>,
+    key_file: Option<&Path>,
     explicit_url: Option<String>,
+    profile: &str,
+    http_options: &HttpOptions,
 ) -> Result<()> {
     use std::io::{self, Write};
 
     // Check if already authenticated
-    let has_key = credentials::resolve_api_key(explicit_key.clone()).is_ok();
+    let has_key = credentials::resolve_api_key(explicit_key.clone(), key_file, profile).is_ok();
 
     if has_key {
         // Already authenticated — show status
-        let api_key = credentials::resolve_api_key(explicit_key)?;
+        let api_key = credentials::resolve_api_key(explicit_key, key_file, profile)?;
         let api_url = explicit_url
-            .or_else(|| credentials::resolve_api_url(None, DEFAULT_API_URL))
+            .or_else(|| credentials::resolve_api_url(None, DEFAULT_API_URL, profile))
             .unwrap_or_else(|| DEFAULT_API_URL.to_string());
 
-        let masked = if api_key.len() > 8 {
-            format!("{}...{}", &api_key[..4], &api_key[api_key.len() - 4..])
-        } else {
-            "****".to_string()
-        };
+        let masked = credentials::mask_api_key(&api_key);
 
         println!("{}", "Canny CLI".bold());
         println!();
+        println!("  {} {}", "Profile:".dimmed(), profile);
         println!("  {} {}", "API URL:".dimmed(), api_url);
         println!("  {} {}", "API key:".dimmed(), masked);
 
         // Verify credentials with a lightweight API call
         print!("  {}", "Verifying...".dimmed());
         io::stdout().flush()?;
-        let client = CannyClient::new(api_url, api_key);
+        let client = CannyClient::with_http_options(api_url, api_key, http_options)?;
         match client.list_boards().await {
             Ok(boards) => {
                 println!(
@@ -1568,10 +2941,12 @@ async fn handle_auth(
                     "✗".red().bold(),
                     e
                 );
-                println!(
-                    "\n  Run {} to re-authenticate.",
-                    "canny auth --reset".cyan()
-                );
+                let reset_cmd = if profile == credentials::DEFAULT_PROFILE {
+                    "canny auth --reset".to_string()
+                } else {
+                    format!("canny auth --reset --profile {}", profile)
+                };
+                println!("\n  Run {} to re-authenticate.", reset_cmd.cyan());
             }
         }
 
@@ -1581,6 +2956,10 @@ async fn handle_auth(
     // Not authenticated — prompt for credentials
     println!("{}", "Canny CLI Authentication".bold());
     println!();
+    if profile != credentials::DEFAULT_PROFILE {
+        println!("  {} {}", "Profile:".dimmed(), profile);
+        println!();
+    }
 
     print!(
         "  {} (e.g. 'mycompany' for mycompany.canny.io) [{}]: ",
@@ -1609,17 +2988,321 @@ async fn handle_auth(
         anyhow::bail!("API key cannot be empty");
     }
 
-    credentials::store_api_key(api_key)?;
-    credentials::store_api_url(&api_url)?;
+    let backend = credentials::store_credentials(api_key, &api_url, profile)?;
 
     println!();
-    println!("  {} Credentials saved to Keychain.", "✓".green().bold());
+    println!("  {} Credentials saved to {}.", "✓".green().bold(), backend);
+    println!("  {} {}", "API URL:".dimmed(), api_url);
+
+    Ok(())
+}
+
+async fn handle_whoami(
+    client: &CannyClient,
+    profile: &str,
+    api_url: &str,
+    masked_key: &str,
+    key_source: &str,
+    json_output: bool,
+) -> Result<()> {
+    let boards = client.list_boards().await;
+    let v2 = client.list_companies(Some(1), None, None, None).await;
+
+    if json_output {
+        let output = serde_json::json!({
+            "profile": profile,
+            "apiURL": api_url,
+            "apiKey": masked_key,
+            "keySource": key_source,
+            "boards": boards.as_ref().ok().map(|b| b.len()),
+            "boardsError": boards.as_ref().err().map(|e| e.to_string()),
+            "v2Available": v2.is_ok(),
+            "v2Error": v2.as_ref().err().map(|e| e.to_string()),
+        });
+        println!("{}", output::json_string(&output)?);
+        return Ok(());
+    }
+
+    println!("  {} {}", "Profile:".dimmed(), profile);
     println!("  {} {}", "API URL:".dimmed(), api_url);
+    println!("  {} {}", "API key:".dimmed(), masked_key);
+    println!("  {} {}", "Source:".dimmed(), key_source);
+
+    match boards {
+        Ok(boards) => println!(
+            "  {} {} board{} accessible",
+            "✓".green().bold(),
+            boards.len(),
+            if boards.len() == 1 { "" } else { "s" }
+        ),
+        Err(e) => println!("  {} Boards: {}", "✗".red().bold(), e),
+    }
+    match v2 {
+        Ok(_) => println!("  {} v2 endpoints available", "✓".green().bold()),
+        Err(e) => println!("  {} v2 endpoints: {}", "✗".red().bold(), e),
+    }
+
+    Ok(())
+}
+
+async fn handle_api(client: &CannyClient, path: &str, data: Option<&str>) -> Result<()> {
+    let body = match data {
+        Some(data) => {
+            serde_json::from_str(&resolve_text_arg(data)?).context("Invalid JSON for --data")?
+        }
+        None => serde_json::json!({}),
+    };
+
+    let text = client.request(path, body).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
+    match serde_json::from_str::<serde_json::Value>(&text) {
+        Ok(value) => output::print_json(&value)?,
+        Err(_) => println!("{}", text),
+    }
+
+    Ok(())
+}
+
+fn handle_version(json_output: bool) -> Result<()> {
+    let version = env!("CARGO_PKG_VERSION");
+    let git_sha = env!("CANNY_GIT_SHA");
+    let build_date = env!("CANNY_BUILD_DATE");
+    let rustc = env!("CANNY_RUSTC_VERSION");
+
+    if json_output {
+        let output = serde_json::json!({
+            "version": version,
+            "git_sha": git_sha,
+            "build_date": build_date,
+            "rustc": rustc,
+        });
+        println!("{}", output::json_string(&output)?);
+        return Ok(());
+    }
+
+    println!("canny {}", version);
+    println!("  {} {}", "Commit:".dimmed(), git_sha);
+    println!("  {} {}", "Built:".dimmed(), build_date);
+    println!("  {} {}", "Rustc:".dimmed(), rustc);
+
+    Ok(())
+}
+
+fn handle_auth_list(active_profile: &str, json_output: bool) -> Result<()> {
+    let profiles = credentials::list_profiles();
+
+    if json_output {
+        let items: Vec<serde_json::Value> = profiles
+            .iter()
+            .map(|profile| {
+                serde_json::json!({
+                    "profile": profile,
+                    "active": profile == active_profile,
+                    "apiURL": credentials::resolve_api_url(None, DEFAULT_API_URL, profile),
+                    "apiKey": credentials::resolve_api_key(None, None, profile)
+                        .ok()
+                        .map(|k| credentials::mask_api_key(&k)),
+                })
+            })
+            .collect();
+        println!("{}", output::json_string(&items)?);
+        return Ok(());
+    }
+
+    if profiles.is_empty() {
+        println!("No profiles found. Run `canny auth` to configure.");
+        return Ok(());
+    }
+
+    println!("{}", "Profiles:".bold());
+    for profile in &profiles {
+        let marker = if profile == active_profile {
+            "*".green().bold().to_string()
+        } else {
+            " ".to_string()
+        };
+        let api_url = credentials::resolve_api_url(None, DEFAULT_API_URL, profile)
+            .unwrap_or_else(|| DEFAULT_API_URL.to_string());
+        let masked = credentials::resolve_api_key(None, None, profile)
+            .map(|k| credentials::mask_api_key(&k))
+            .unwrap_or_else(|_| "(not set)".to_string());
+
+        println!("\n{} {}", marker, profile.cyan());
+        println!("    API URL: {}", api_url);
+        println!("    API key: {}", masked.dimmed());
+    }
+
+    Ok(())
+}
+
+fn handle_auth_export(profile: &str, show_secret: bool, json_output: bool) -> Result<()> {
+    if !show_secret {
+        anyhow::bail!("Refusing to print the API key in plain text without --show-secret");
+    }
+
+    let api_key = credentials::resolve_api_key(None, None, profile)
+        .map_err(|_| anyhow::anyhow!("No stored credentials for profile '{}'", profile))?;
+    let api_url = credentials::resolve_api_url(None, DEFAULT_API_URL, profile)
+        .unwrap_or_else(|| DEFAULT_API_URL.to_string());
+
+    if json_output {
+        println!(
+            "{}",
+            output::json_string(&serde_json::json!({
+                "profile": profile,
+                "apiURL": api_url,
+                "apiKey": api_key,
+            }))?
+        );
+    } else {
+        let profile_arg = if profile == credentials::DEFAULT_PROFILE {
+            String::new()
+        } else {
+            format!(" --profile {}", shell_quote(profile))
+        };
+        println!(
+            "canny auth import{} --api-url {} --api-key {}",
+            profile_arg,
+            shell_quote(&api_url),
+            shell_quote(&api_key)
+        );
+    }
 
     Ok(())
 }
 
-async fn handle_posts(client: &CannyClient, cmd: PostsCommands, json_output: bool) -> Result<()> {
+/// Quote a value for a POSIX shell command line
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+fn handle_auth_import(
+    explicit_key: Option<String>,
+    key_file: Option<&Path>,
+    explicit_url: Option<String>,
+    profile: &str,
+    json_output: bool,
+) -> Result<()> {
+    let api_key = match (explicit_key, key_file) {
+        (Some(key), _) => key,
+        (None, Some(path)) => credentials::read_api_key_file(path)?,
+        (None, None) => anyhow::bail!("`canny auth import` requires --api-key or --api-key-file"),
+    };
+    let api_key = api_key.trim();
+    if api_key.is_empty() {
+        anyhow::bail!("API key cannot be empty");
+    }
+    let api_url = explicit_url.unwrap_or_else(|| DEFAULT_API_URL.to_string());
+
+    let backend = credentials::store_credentials(api_key, &api_url, profile)?;
+
+    if json_output {
+        println!(
+            "{}",
+            output::json_string(&serde_json::json!({
+                "success": true,
+                "profile": profile,
+                "apiURL": api_url,
+                "backend": backend.to_string(),
+            }))?
+        );
+    } else if !output::is_quiet() {
+        println!(
+            "{} Credentials for profile {} saved to {}.",
+            "✓".green(),
+            profile.cyan(),
+            backend
+        );
+    }
+
+    Ok(())
+}
+
+async fn handle_export(
+    client: &CannyClient,
+    board_id: &str,
+    out: &str,
+    include_comments: bool,
+    include_votes: bool,
+    json_output: bool,
+) -> Result<()> {
+    use std::io::{self, BufWriter, Write};
+
+    let to_stdout = out == "-";
+    let mut writer: Box<dyn Write> = if to_stdout {
+        Box::new(BufWriter::new(io::stdout().lock()))
+    } else {
+        let file = std::fs::File::create(out)
+            .with_context(|| format!("Failed to create {}", out))?;
+        Box::new(BufWriter::new(file))
+    };
+
+    // Posts written to stdout would be drawn over by the spinner
+    let progress = if to_stdout {
+        output::Progress::hidden()
+    } else {
+        output::Progress::spinner("Exporting posts...")
+    };
+    let mut exported = 0;
+    client
+        .for_each_post_page(board_id, None, None, None, None, None, None, async |posts| {
+            for post in &posts {
+                let mut record = serde_json::to_value(post)?;
+                if include_comments {
+                    let comments = client.list_all_comments(&post.id).await?;
+                    record["comments"] = serde_json::to_value(comments)?;
+                }
+                if include_votes {
+                    let votes = client.list_all_votes(&post.id).await?;
+                    record["votes"] = serde_json::to_value(votes)?;
+                }
+                // Past the deadline this post's comments and votes may have
+                // been cut short, so the export ends before it
+                if client.deadline_passed() {
+                    break;
+                }
+                serde_json::to_writer(&mut writer, &record)?;
+                writeln!(writer)?;
+                exported += 1;
+                progress.inc();
+            }
+            Ok(())
+        })
+        .await?;
+
+    writer.flush()?;
+    drop(progress);
+
+    if to_stdout {
+        return Ok(());
+    }
+
+    if json_output {
+        println!(
+            "{}",
+            output::json_string(&serde_json::json!({
+                "success": true,
+                "posts": exported,
+                "complete": !client.results_incomplete(),
+                "path": out,
+            }))?
+        );
+    } else {
+        println!("{} Exported {} posts to {}", "✓".green(), exported, out);
+    }
+
+    Ok(())
+}
+
+async fn handle_posts(
+    client: &CannyClient,
+    cmd: PostsCommands,
+    format: OutputFormat,
+) -> Result<()> {
+    let json_output = format.is_json();
+
     match cmd {
         PostsCommands::List {
             board_id,
@@ -1627,11 +3310,45 @@ async fn handle_posts(client: &CannyClient, cmd: PostsCommands, json_output: boo
             skip,
             sort,
             status,
+            status_not,
             author_id,
             search,
             company_id,
             tag_ids,
+            all,
+            count_only,
+            min_score,
+            max_score,
+            min_comments,
+            created_after,
+            created_before,
+            weight_by_spend,
+            concurrency,
+            group_by,
         } => {
+            if weight_by_spend && matches!(format, OutputFormat::Csv | OutputFormat::Table) {
+                anyhow::bail!("--weight-by-spend supports only text and JSON output");
+            }
+            if group_by.is_some() && format != OutputFormat::Text {
+                anyhow::bail!("--group-by supports only text output");
+            }
+            // Relevance ranks posts against the search term, so it means
+            // nothing without one
+            let sort = match (sort, &search) {
+                (Some(PostSort::Relevance), None) => {
+                    anyhow::bail!("--sort relevance requires --search to rank posts against")
+                }
+                (Some(sort), Some(_)) if !matches!(sort, PostSort::Relevance) => {
+                    eprintln!(
+                        "{} the API may rank --search results by relevance despite --sort {}",
+                        "warning:".yellow(),
+                        sort
+                    );
+                    sort
+                }
+                (Some(sort), _) => sort,
+                (None, _) => PostSort::Newest,
+            };
             let status_str = if status.is_empty() {
                 None
             } else {
@@ -1643,30 +3360,162 @@ async fn handle_posts(client: &CannyClient, cmd: PostsCommands, json_output: boo
             } else {
                 Some(tag_ids.iter().map(|s| s.as_str()).collect())
             };
-            let response = client
-                .list_posts(
-                    &board_id,
-                    Some(limit),
-                    Some(skip),
-                    Some(&sort.to_string()),
-                    status_str.as_deref(),
-                    author_id.as_deref(),
-                    search.as_deref(),
-                    company_id.as_deref(),
-                    tag_ids_refs,
-                )
-                .await?;
+            // The API filters by a single author, so several authors are
+            // fetched with one request each and merged
+            let authors: Vec<Option<&str>> = if author_id.is_empty() {
+                vec![None]
+            } else {
+                author_id.iter().map(|a| Some(a.as_str())).collect()
+            };
+            // Posts without a parseable creation date never match a date filter
+            let date_filtered = created_after.is_some() || created_before.is_some();
+            let excluded_status = |post: &models::CannyPost| {
+                post.status
+                    .as_deref()
+                    .is_some_and(|status| status_not.iter().any(|s| s.eq_ignore_ascii_case(status)))
+            };
+            let matches_filters = |post: &models::CannyPost| {
+                !excluded_status(post)
+                    && min_score.is_none_or(|min| post.score >= min)
+                    && max_score.is_none_or(|max| post.score <= max)
+                    && min_comments.is_none_or(|min| post.comment_count >= min)
+                    && (!date_filtered
+                        || post_created(post).is_some_and(|created| {
+                            created_after.is_none_or(|after| created >= after)
+                                && created_before.is_none_or(|before| created < before)
+                        }))
+            };
 
-            if json_output {
-                println!("{}", serde_json::to_string_pretty(&response.posts)?);
+            if all && format == OutputFormat::Ndjson && !weight_by_spend && !count_only {
+                // Print each page as it arrives instead of collecting every
+                // post; posts by several of the authors are printed once
+                let sort_str = sort.to_string();
+                let mut seen = HashSet::new();
+                for author in &authors {
+                    client
+                        .for_each_post_page(
+                            &board_id,
+                            Some(&sort_str),
+                            status_str.as_deref(),
+                            *author,
+                            search.as_deref(),
+                            company_id.as_deref(),
+                            tag_ids_refs.clone(),
+                            async |posts| {
+                                for post in posts.iter().filter(|post| matches_filters(post)) {
+                                    if authors.len() == 1 || seen.insert(post.id.clone()) {
+                                        output::print_json_line(post)?;
+                                    }
+                                }
+                                Ok(())
+                            },
+                        )
+                        .await?;
+                }
+                return Ok(());
+            }
+
+            if all {
+                let sort_str = sort.to_string();
+                let progress = output::Progress::spinner("Fetching posts...");
+                let mut posts = Vec::new();
+                for author in &authors {
+                    let fetched = posts.len();
+                    let author_posts = client
+                        .list_all_posts(
+                            &board_id,
+                            Some(&sort_str),
+                            status_str.as_deref(),
+                            *author,
+                            search.as_deref(),
+                            company_id.as_deref(),
+                            tag_ids_refs.clone(),
+                            Some(|count: usize| progress.set(fetched + count)),
+                        )
+                        .await?;
+                    posts.extend(author_posts);
+                }
+                drop(progress);
+                if authors.len() > 1 {
+                    merge_author_posts(&mut posts, &sort);
+                }
+                posts.retain(|post| matches_filters(post));
+
+                if weight_by_spend {
+                    let posts = weight_posts_by_spend(client, posts, concurrency as usize).await?;
+                    return print_weighted_posts(&posts, json_output);
+                }
+
+                if count_only {
+                    print_count(client, posts.len(), json_output)?;
+                } else if format == OutputFormat::Csv {
+                    print_posts_csv(&posts);
+                } else if json_output {
+                    output::print_json_list(&posts, false, None, None)?;
+                } else if posts.is_empty() {
+                    println!("No posts found.");
+                } else if let Some(group_by) = group_by {
+                    print_grouped_posts(posts, group_by);
+                } else {
+                    println!("{} ({} total)", "Posts:".bold(), posts.len());
+                    if format == OutputFormat::Table {
+                        print_posts_table(&posts);
+                    } else {
+                        for post in &posts {
+                            print_post_summary(post);
+                        }
+                    }
+                }
+                return Ok(());
+            }
+
+            let mut posts = Vec::new();
+            let mut has_more = false;
+            for author in &authors {
+                let (response, _) = client
+                    .list_posts(
+                        &board_id,
+                        Some(limit),
+                        Some(skip),
+                        Some(&sort.to_string()),
+                        status_str.as_deref(),
+                        *author,
+                        search.as_deref(),
+                        company_id.as_deref(),
+                        tag_ids_refs.clone(),
+                    )
+                    .await?;
+                has_more |= response.has_more;
+                posts.extend(response.posts);
+            }
+            if authors.len() > 1 {
+                merge_author_posts(&mut posts, &sort);
+            }
+            posts.retain(|post| matches_filters(post));
+
+            if weight_by_spend {
+                let posts = weight_posts_by_spend(client, posts, concurrency as usize).await?;
+                return print_weighted_posts(&posts, json_output);
+            }
+
+            if format == OutputFormat::Csv {
+                print_posts_csv(&posts);
+            } else if json_output {
+                output::print_json_list(&posts, has_more, None, Some(skip + limit))?;
             } else {
-                if response.posts.is_empty() {
+                if posts.is_empty() {
                     println!("No posts found.");
                 } else {
-                    for post in &response.posts {
-                        print_post_summary(post);
+                    if let Some(group_by) = group_by {
+                        print_grouped_posts(posts, group_by);
+                    } else if format == OutputFormat::Table {
+                        print_posts_table(&posts);
+                    } else {
+                        for post in &posts {
+                            print_post_summary(post);
+                        }
                     }
-                    if response.has_more {
+                    if has_more {
                         println!(
                             "\n{} Use --skip {} to see more.",
                             "More posts available.".dimmed(),
@@ -1677,26 +3526,162 @@ async fn handle_posts(client: &CannyClient, cmd: PostsCommands, json_output: boo
             }
         }
 
-        PostsCommands::Get { id, url_name, board_id } => {
-            if id.is_none() && url_name.is_none() {
-                anyhow::bail!("Either --id or --url-name must be provided");
+        PostsCommands::ListAllBoards {
+            search,
+            status,
+            limit,
+            sort,
+            concurrency,
+        } => {
+            let status_str = if status.is_empty() {
+                None
+            } else {
+                Some(status.join(","))
+            };
+            let boards = client.list_boards().await?;
+
+            // Fetch boards concurrently; one board failing doesn't stop the rest
+            let mut results: Vec<_> = futures::stream::iter(boards.iter().enumerate())
+                .map(|(index, board)| {
+                    let status_str = status_str.as_deref();
+                    let search = search.as_deref();
+                    async move {
+                        let result = client
+                            .list_posts(
+                                &board.id,
+                                Some(limit),
+                                None,
+                                Some(sort.api_sort()),
+                                status_str,
+                                None,
+                                search,
+                                None,
+                                None,
+                            )
+                            .await;
+                        (index, board, result)
+                    }
+                })
+                .buffer_unordered(concurrency as usize)
+                .collect()
+                .await;
+            // Restore board order so ties in the merged sort are deterministic
+            results.sort_by_key(|(index, _, _)| *index);
+
+            let mut posts: Vec<models::CannyPost> = Vec::new();
+            let mut failures: Vec<(&models::CannyBoard, anyhow::Error)> = Vec::new();
+            for (_, board, result) in results {
+                match result {
+                    Ok((response, _)) => {
+                        for mut post in response.posts {
+                            post.board.get_or_insert_with(|| board.clone());
+                            posts.push(post);
+                        }
+                    }
+                    Err(e) => failures.push((board, e)),
+                }
+            }
+            sort_merged_posts(&mut posts, sort);
+
+            if json_output {
+                output::print_json_list(&posts, false, None, None)?;
+            } else if posts.is_empty() {
+                println!("No posts found.");
+            } else {
+                println!(
+                    "{} ({} from {} boards)",
+                    "Posts:".bold(),
+                    posts.len(),
+                    boards.len()
+                );
+                for post in &posts {
+                    print_post_summary(post);
+                    if let Some(ref board) = post.board {
+                        println!("  Board: {}", board.name.cyan());
+                    }
+                }
+            }
+
+            if !failures.is_empty() {
+                eprintln!(
+                    "\n{} Failed to fetch {} of {} boards:",
+                    "✗".red(),
+                    failures.len(),
+                    boards.len()
+                );
+                for (board, e) in &failures {
+                    eprintln!("  {} ({}): {}", board.name, board.id.dimmed(), e);
+                }
+                return Err(PartialFailure.into());
             }
+        }
+
+        PostsCommands::Get {
+            id,
+            url_name,
+            board_id,
+            with_comments,
+            show_voters,
+        } => {
+            let id = if id.is_none() && url_name.is_none() {
+                if !is_interactive() {
+                    anyhow::bail!("Either --id or --url-name must be provided");
+                }
+                Some(client.lookup(pick_post(client, board_id.as_deref())).await?)
+            } else {
+                id
+            };
             let post = client.get_post(id.as_deref(), url_name.as_deref(), board_id.as_deref()).await?;
-            if let Some(post) = post {
-                if json_output {
-                    println!("{}", serde_json::to_string_pretty(&post)?);
-                } else {
-                    print_post_detail(&post);
+            let Some(post) = post else {
+                return Err(NotFound("Post").into());
+            };
+            let comments = if with_comments {
+                Some(client.list_all_comments(&post.id).await?)
+            } else {
+                None
+            };
+            let voters: Option<Vec<models::CannyUser>> = if show_voters {
+                let votes = client.list_all_votes(&post.id).await?;
+                Some(votes.into_iter().filter_map(|vote| vote.voter).collect())
+            } else {
+                None
+            };
+
+            if json_output {
+                let mut record = serde_json::to_value(&post)?;
+                if let Some(ref comments) = comments {
+                    record["comments"] = serde_json::to_value(comments)?;
                 }
+                if let Some(ref voters) = voters {
+                    record["voters"] = serde_json::to_value(voters)?;
+                }
+                output::print_json(&record)?;
             } else {
-                eprintln!("{}", "Post not found.".red());
-                std::process::exit(1);
+                print_post_detail(&post);
+                if let Some(ref comments) = comments {
+                    println!("\n{} ({})", "Comments:".bold(), comments.len());
+                    if comments.is_empty() {
+                        println!("No comments yet.");
+                    } else {
+                        print_comment_tree(comments);
+                    }
+                }
+                if let Some(ref voters) = voters {
+                    println!("\n{} ({})", "Voters:".bold(), voters.len());
+                    if voters.is_empty() {
+                        println!("No votes yet.");
+                    }
+                    for voter in voters {
+                        print_voter(voter);
+                    }
+                }
             }
         }
 
         PostsCommands::Create {
             board_id,
             author_id,
+            author_email,
             title,
             details,
             category_id,
@@ -1707,37 +3692,76 @@ async fn handle_posts(client: &CannyClient, cmd: PostsCommands, json_output: boo
             owner_id,
             image_urls,
             created_at,
+            return_mode,
+            input_json,
         } => {
-            // Parse custom_fields JSON if provided
-            let custom_fields_json: Option<serde_json::Value> = match custom_fields {
-                Some(ref cf) => Some(serde_json::from_str(cf).context("Invalid JSON for --custom-fields")?),
-                None => None,
+            let id = if let Some(input) = input_json {
+                let body = read_input_json(&input, &["boardID", "authorID", "title"])?;
+                client
+                    .create_from_body("posts/create", body)
+                    .await?
+            } else {
+                let board_id =
+                    board_id.context("Either --board-id or --input-json must be provided")?;
+                let title = title.context("Either --title or --input-json must be provided")?;
+                let author_id = match (author_id, author_email) {
+                    (Some(id), _) => id,
+                    (None, Some(email)) => user_id_for_email(client, &email).await?,
+                    (None, None) => {
+                        anyhow::bail!("Either --author-id or --author-email must be provided")
+                    }
+                };
+                let details = details.as_deref().map(resolve_text_arg).transpose()?;
+                // Parse custom_fields JSON if provided
+                let custom_fields_json: Option<serde_json::Value> = match custom_fields {
+                    Some(ref cf) => Some(serde_json::from_str(cf).context("Invalid JSON for --custom-fields")?),
+                    None => None,
+                };
+                // Convert Vec<String> to Vec<&str> for image_urls
+                let image_urls_refs: Option<Vec<&str>> = if image_urls.is_empty() {
+                    None
+                } else {
+                    Some(image_urls.iter().map(|s| s.as_str()).collect())
+                };
+                client
+                    .create_post(
+                        &board_id,
+                        &author_id,
+                        &title,
+                        details.as_deref(),
+                        category_id.as_deref(),
+                        by_id.as_deref(),
+                        custom_fields_json,
+                        eta.as_deref(),
+                        eta_public,
+                        owner_id.as_deref(),
+                        image_urls_refs,
+                        created_at.as_deref(),
+                    )
+                    .await?
             };
-            // Convert Vec<String> to Vec<&str> for image_urls
-            let image_urls_refs: Option<Vec<&str>> = if image_urls.is_empty() {
-                None
+
+            // Canny only returns the ID, so the full post is fetched again
+            let post = if return_mode == CreateReturn::Full
+                && !output::is_quiet()
+                && !client.is_dry_run()
+            {
+                client.get_post(Some(&id), None, None).await?
             } else {
-                Some(image_urls.iter().map(|s| s.as_str()).collect())
+                None
             };
-            let id = client
-                .create_post(
-                    &board_id,
-                    &author_id,
-                    &title,
-                    details.as_deref(),
-                    category_id.as_deref(),
-                    by_id.as_deref(),
-                    custom_fields_json,
-                    eta.as_deref(),
-                    eta_public,
-                    owner_id.as_deref(),
-                    image_urls_refs,
-                    created_at.as_deref(),
-                )
-                .await?;
 
-            if json_output {
+            if let Some(post) = post {
+                if json_output {
+                    output::print_json(&post)?;
+                } else {
+                    println!("{} Created post with ID: {}", "✓".green(), id.cyan());
+                    print_post_detail(&post);
+                }
+            } else if json_output {
                 println!(r#"{{"id": "{}"}}"#, id);
+            } else if output::is_quiet() {
+                println!("{}", id);
             } else {
                 println!("{} Created post with ID: {}", "✓".green(), id.cyan());
             }
@@ -1769,7 +3793,7 @@ async fn handle_posts(client: &CannyClient, cmd: PostsCommands, json_output: boo
 
             if json_output {
                 println!(r#"{{"success": true}}"#);
-            } else {
+            } else if !output::is_quiet() {
                 println!(
                     "{} Status changed to: {}",
                     "✓".green(),
@@ -1783,12 +3807,21 @@ async fn handle_posts(client: &CannyClient, cmd: PostsCommands, json_output: boo
 
             if json_output {
                 println!(r#"{{"success": true}}"#);
-            } else {
+            } else if !output::is_quiet() {
                 println!("{} Category updated.", "✓".green());
             }
         }
 
-        PostsCommands::Update { id, title, details, eta, eta_public, custom_fields } => {
+        PostsCommands::Update {
+            id,
+            title,
+            details,
+            eta,
+            eta_public,
+            custom_fields,
+            owner_id,
+        } => {
+            let details = details.as_deref().map(resolve_text_arg).transpose()?;
             let custom_fields_json: Option<serde_json::Value> = custom_fields
                 .as_ref()
                 .map(|s| serde_json::from_str(s))
@@ -1803,34 +3836,238 @@ async fn handle_posts(client: &CannyClient, cmd: PostsCommands, json_output: boo
                     eta.as_deref(),
                     eta_public,
                     custom_fields_json,
+                    owner_id.as_deref(),
                 )
                 .await?;
 
             if json_output {
                 println!(r#"{{"success": true}}"#);
-            } else {
+            } else if !output::is_quiet() {
                 println!("{} Post updated.", "✓".green());
             }
         }
 
-        PostsCommands::Delete { id } => {
+        PostsCommands::Delete { id, from_file, yes } => {
+            if let Some(path) = from_file {
+                return delete_posts_from_file(client, &path, yes, json_output).await;
+            }
+
+            let id = id.context("Either --id or --from-file must be provided")?;
             client.delete_post(&id).await?;
 
             if json_output {
                 println!(r#"{{"success": true}}"#);
-            } else {
+            } else if !output::is_quiet() {
                 println!("{} Post deleted.", "✓".green());
             }
         }
 
-        PostsCommands::AddTag { id, tag_id } => {
-            client.add_post_tag(&id, &tag_id).await?;
-
-            if json_output {
-                println!(r#"{{"success": true}}"#);
-            } else {
-                println!("{} Tag added to post.", "✓".green());
-            }
+        PostsCommands::StatusBulk {
+            board_id,
+            from_file,
+            filter_status,
+            changer_id,
+            status,
+            notify,
+            comment,
+            yes,
+        } => {
+            let ids = match (from_file, filter_status) {
+                (Some(path), _) => read_post_ids(&path)?,
+                (None, Some(filter_status)) => {
+                    let board_id = board_id.context("--filter-status requires --board-id")?;
+                    client
+                        .list_all_posts(
+                            &board_id,
+                            None,
+                            Some(&filter_status),
+                            None,
+                            None,
+                            None,
+                            None,
+                            None::<fn(usize)>,
+                        )
+                        .await?
+                        .into_iter()
+                        .map(|post| post.id)
+                        .collect()
+                }
+                (None, None) => anyhow::bail!("Either --from-file or --filter-status must be provided"),
+            };
+
+            let change = BulkStatusChange {
+                status: &status,
+                changer_id: &changer_id,
+                notify,
+                comment: comment.as_deref(),
+            };
+            return change_posts_status(client, &ids, &change, yes, json_output).await;
+        }
+
+        PostsCommands::Import { board_id, file } => {
+            return import_posts(client, &board_id, &file, json_output).await;
+        }
+
+        PostsCommands::Watch { id, interval, until } => {
+            let mut last_status: Option<String> = None;
+
+            loop {
+                let post = match client.get_post(Some(&id), None, None).await? {
+                    Some(post) => post,
+                    None => {
+                        return Err(NotFound("Post").into());
+                    }
+                };
+                let status = post.status.unwrap_or_else(|| "unknown".to_string());
+
+                if last_status.as_deref() != Some(status.as_str()) {
+                    let time = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+                    if json_output {
+                        println!(
+                            "{}",
+                            serde_json::to_string(&serde_json::json!({
+                                "time": time,
+                                "id": id,
+                                "status": status,
+                                "previous": last_status,
+                            }))?
+                        );
+                    } else {
+                        match last_status {
+                            Some(ref previous) => println!(
+                                "{} {} {} → {}",
+                                time.dimmed(),
+                                post.title.bold(),
+                                previous,
+                                status.cyan()
+                            ),
+                            None => println!(
+                                "{} {} {}",
+                                time.dimmed(),
+                                post.title.bold(),
+                                status.cyan()
+                            ),
+                        }
+                    }
+                    last_status = Some(status.clone());
+                }
+
+                if until.as_deref().is_some_and(|u| u.eq_ignore_ascii_case(&status)) {
+                    break;
+                }
+
+                tokio::select! {
+                    _ = tokio::time::sleep(Duration::from_secs(interval)) => {}
+                    _ = tokio::signal::ctrl_c() => break,
+                }
+            }
+        }
+
+        PostsCommands::Stats { board_id } => {
+            let progress = output::Progress::spinner("Fetching posts...");
+            let posts = client
+                .list_all_posts(
+                    &board_id,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    Some(|count: usize| progress.set(count)),
+                )
+                .await?;
+            drop(progress);
+
+            print_post_stats(&posts, json_output)?;
+        }
+
+        PostsCommands::Overdue { board_id } => {
+            let progress = output::Progress::spinner("Fetching posts...");
+            let posts = client
+                .list_all_posts(
+                    &board_id,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    Some(|count: usize| progress.set(count)),
+                )
+                .await?;
+            drop(progress);
+
+            let overdue = overdue_posts(posts, Utc::now());
+            print_overdue_posts(&overdue, json_output)?;
+        }
+
+        PostsCommands::FindSimilar {
+            board_id,
+            title,
+            threshold,
+            limit,
+        } => {
+            let (response, _) = client
+                .list_posts(
+                    &board_id,
+                    Some(limit),
+                    None,
+                    Some(&PostSort::Relevance.to_string()),
+                    None,
+                    None,
+                    Some(&title),
+                    None,
+                    None,
+                )
+                .await?;
+            let mut similar: Vec<(f64, models::CannyPost)> = response
+                .posts
+                .into_iter()
+                .map(|post| (similarity::title_similarity(&title, &post.title), post))
+                .filter(|(similarity, _)| *similarity >= threshold)
+                .collect();
+            similar.sort_by(|a, b| b.0.total_cmp(&a.0));
+            print_similar_posts(&similar, json_output)?;
+        }
+
+        PostsCommands::Merge { id, merge_into, yes } => {
+            if id == merge_into {
+                anyhow::bail!("Cannot merge a post into itself");
+            }
+            if !yes
+                && !client.is_dry_run()
+                && !confirm(&format!("Merge post {} into {}?", id, merge_into))?
+            {
+                println!("Aborted.");
+                return Ok(());
+            }
+
+            client.merge_posts(&id, &merge_into).await?;
+
+            if output::is_quiet() {
+                println!("{}", merge_into);
+            } else if json_output {
+                println!(
+                    "{}",
+                    output::json_string(&serde_json::json!({
+                        "success": true,
+                        "id": merge_into,
+                    }))?
+                );
+            } else {
+                println!("{} Post merged into {}", "✓".green(), merge_into.cyan());
+            }
+        }
+
+        PostsCommands::AddTag { id, tag_id } => {
+            client.add_post_tag(&id, &tag_id).await?;
+
+            if json_output {
+                println!(r#"{{"success": true}}"#);
+            } else if !output::is_quiet() {
+                println!("{} Tag added to post.", "✓".green());
+            }
         }
 
         PostsCommands::RemoveTag { id, tag_id } => {
@@ -1838,7 +4075,7 @@ async fn handle_posts(client: &CannyClient, cmd: PostsCommands, json_output: boo
 
             if json_output {
                 println!(r#"{{"success": true}}"#);
-            } else {
+            } else if !output::is_quiet() {
                 println!("{} Tag removed from post.", "✓".green());
             }
         }
@@ -1848,7 +4085,7 @@ async fn handle_posts(client: &CannyClient, cmd: PostsCommands, json_output: boo
 
             if json_output {
                 println!(r#"{{"success": true}}"#);
-            } else {
+            } else if !output::is_quiet() {
                 println!(
                     "{} Jira issue {} linked to post.",
                     "✓".green(),
@@ -1862,7 +4099,7 @@ async fn handle_posts(client: &CannyClient, cmd: PostsCommands, json_output: boo
 
             if json_output {
                 println!(r#"{{"success": true}}"#);
-            } else {
+            } else if !output::is_quiet() {
                 println!(
                     "{} Jira issue {} unlinked from post.",
                     "✓".green(),
@@ -1888,8 +4125,10 @@ async fn handle_comments(
             company_id,
             limit,
             skip,
+            internal,
+            public,
         } => {
-            let response = client
+            let (mut response, _) = client
                 .list_comments(
                     post_id.as_deref(),
                     author_id.as_deref(),
@@ -1899,16 +4138,24 @@ async fn handle_comments(
                     Some(skip),
                 )
                 .await?;
+            if internal || public {
+                response
+                    .comments
+                    .retain(|c| c.internal.unwrap_or(false) == internal);
+            }
 
             if json_output {
-                println!("{}", serde_json::to_string_pretty(&response.comments)?);
+                output::print_json_list(
+                    &response.comments,
+                    response.has_more,
+                    None,
+                    Some(skip + limit),
+                )?;
             } else {
                 if response.comments.is_empty() {
                     println!("No comments found.");
                 } else {
-                    for comment in &response.comments {
-                        print_comment(comment);
-                    }
+                    print_comment_tree(&response.comments);
                     if response.has_more {
                         println!(
                             "\n{} Use --skip {} to see more.",
@@ -1923,36 +4170,57 @@ async fn handle_comments(
         CommentsCommands::Create {
             post_id,
             author_id,
+            author_email,
             value,
             parent_id,
             created_at,
             image_urls,
             internal,
-            notify_voters,
+            notify,
+            input_json,
         } => {
-            let image_urls_refs: Option<Vec<&str>> = if image_urls.is_empty() {
-                None
+            let id = if let Some(input) = input_json {
+                let body = read_input_json(&input, &["postID", "authorID", "value"])?;
+                client
+                    .create_from_body("comments/create", body)
+                    .await?
             } else {
-                Some(image_urls.iter().map(|s| s.as_str()).collect())
-            };
-            let internal_opt = if internal { Some(true) } else { None };
-            let notify_voters_opt = if notify_voters { Some(true) } else { None };
+                let post_id = post_id.context("Either --post-id or --input-json must be provided")?;
+                let author_id = match (author_id, author_email) {
+                    (Some(id), _) => id,
+                    (None, Some(email)) => user_id_for_email(client, &email).await?,
+                    (None, None) => anyhow::bail!(
+                        "Either --author-id, --author-email or --input-json must be provided"
+                    ),
+                };
+                let value = value.context("Either --value or --input-json must be provided")?;
+                let value = resolve_text_arg(&value)?;
+                let image_urls_refs: Option<Vec<&str>> = if image_urls.is_empty() {
+                    None
+                } else {
+                    Some(image_urls.iter().map(|s| s.as_str()).collect())
+                };
+                let internal_opt = if internal { Some(true) } else { None };
+                let notify_opt = if notify { Some(true) } else { None };
 
-            let id = client
-                .create_comment(
-                    &post_id,
-                    &author_id,
-                    &value,
-                    parent_id.as_deref(),
-                    created_at.as_deref(),
-                    image_urls_refs,
-                    internal_opt,
-                    notify_voters_opt,
-                )
-                .await?;
+                client
+                    .create_comment(
+                        &post_id,
+                        &author_id,
+                        &value,
+                        parent_id.as_deref(),
+                        created_at.as_deref(),
+                        image_urls_refs,
+                        internal_opt,
+                        notify_opt,
+                    )
+                    .await?
+            };
 
             if json_output {
                 println!(r#"{{"id": "{}"}}"#, id);
+            } else if output::is_quiet() {
+                println!("{}", id);
             } else {
                 println!("{} Created comment with ID: {}", "✓".green(), id.cyan());
             }
@@ -1962,13 +4230,38 @@ async fn handle_comments(
             let comment = client.get_comment(&id).await?;
             if let Some(comment) = comment {
                 if json_output {
-                    println!("{}", serde_json::to_string_pretty(&comment)?);
+                    output::print_json(&comment)?;
                 } else {
                     print_comment_detail(&comment);
                 }
             } else {
-                eprintln!("{}", "Comment not found.".red());
-                std::process::exit(1);
+                return Err(NotFound("Comment").into());
+            }
+        }
+
+        CommentsCommands::Update {
+            id,
+            value,
+            image_urls,
+        } => {
+            let value = resolve_text_arg(&value)?;
+            let image_urls_refs: Option<Vec<&str>> = if image_urls.is_empty() {
+                None
+            } else {
+                Some(image_urls.iter().map(|s| s.as_str()).collect())
+            };
+
+            client
+                .update_comment(&id, &value, image_urls_refs)
+                .await?;
+
+            if json_output {
+                println!(r#"{{"success": true}}"#);
+            } else if !output::is_quiet() {
+                println!("{} Comment updated.", "✓".green());
+                if let Some(comment) = client.get_comment(&id).await? {
+                    print_comment_detail(&comment);
+                }
             }
         }
 
@@ -1977,7 +4270,7 @@ async fn handle_comments(
 
             if json_output {
                 println!(r#"{{"success": true}}"#);
-            } else {
+            } else if !output::is_quiet() {
                 println!("{} Comment deleted.", "✓".green());
             }
         }
@@ -1986,106 +4279,1208 @@ async fn handle_comments(
     Ok(())
 }
 
-async fn handle_categories(
+async fn handle_categories(
+    client: &CannyClient,
+    cmd: CategoriesCommands,
+    json_output: bool,
+) -> Result<()> {
+    match cmd {
+        CategoriesCommands::List {
+            board_id,
+            limit,
+            skip,
+        } => {
+            let response = client
+                .list_categories(&board_id, Some(limit), Some(skip))
+                .await?;
+
+            if json_output {
+                output::print_json_list(
+                    &response.categories,
+                    response.has_more,
+                    None,
+                    Some(skip + limit),
+                )?;
+            } else {
+                if response.categories.is_empty() {
+                    println!("No categories found.");
+                } else {
+                    println!("{}", "Categories:".bold());
+                    for cat in &response.categories {
+                        println!(
+                            "  {} {} {}",
+                            cat.id.dimmed(),
+                            cat.name.cyan(),
+                            format!("({} posts)", cat.post_count.unwrap_or(0)).dimmed()
+                        );
+                    }
+                }
+            }
+        }
+
+        CategoriesCommands::Get { id } => {
+            let category = client.get_category(&id).await?;
+            if let Some(category) = category {
+                if json_output {
+                    output::print_json(&category)?;
+                } else {
+                    print_category_detail(&category);
+                }
+            } else {
+                return Err(NotFound("Category").into());
+            }
+        }
+
+        CategoriesCommands::Create {
+            board_id,
+            name,
+            parent_id,
+            subscribe_admins,
+        } => {
+            let id = client
+                .create_category(&board_id, &name, parent_id.as_deref(), subscribe_admins)
+                .await?;
+
+            if json_output {
+                println!(r#"{{"id": "{}"}}"#, id);
+            } else if output::is_quiet() {
+                println!("{}", id);
+            } else {
+                println!("{} Created category with ID: {}", "✓".green(), id.cyan());
+            }
+        }
+
+        CategoriesCommands::Update {
+            id,
+            name,
+            parent_id,
+        } => {
+            if name.is_none() && parent_id.is_none() {
+                anyhow::bail!("Nothing to update: provide --name or --parent-id");
+            }
+
+            client
+                .update_category(&id, name.as_deref(), parent_id.as_deref())
+                .await?;
+
+            if json_output {
+                println!(r#"{{"success": true}}"#);
+            } else if !output::is_quiet() {
+                println!("{} Category updated.", "✓".green());
+            }
+        }
+
+        CategoriesCommands::Delete { id } => {
+            client.delete_category(&id).await?;
+
+            if json_output {
+                println!(r#"{{"success": true}}"#);
+            } else if !output::is_quiet() {
+                println!("{} Category deleted.", "✓".green());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn print_post_summary(post: &models::CannyPost) {
+    let status = post.status.as_deref().unwrap_or("unknown").to_uppercase();
+
+    let status_colored = match status.to_lowercase().as_str() {
+        "open" => status.yellow(),
+        "planned" | "in progress" => status.blue(),
+        "complete" => status.green(),
+        "closed" => status.red(),
+        _ => status.white(),
+    };
+
+    println!("\n{} {}", post.id.dimmed(), post.title.bold());
+    println!(
+        "  {} | {} votes | {} comments",
+        status_colored,
+        post.score.to_string().cyan(),
+        post.comment_count.to_string().cyan()
+    );
+    if let Some(ref cat) = post.category {
+        println!("  Category: {}", cat.name.magenta());
+    }
+    if !post.tags.is_empty() {
+        println!("  Tags: {}", format_tags(&post.tags));
+    }
+}
+
+/// Pair each post with the summed monthly spend of the companies its voters
+/// belong to, each company counted once per post, highest first. Spend
+/// embedded in the vote is used when present, otherwise the company's.
+async fn weight_posts_by_spend(
+    client: &CannyClient,
+    posts: Vec<models::CannyPost>,
+    concurrency: usize,
+) -> Result<Vec<(f64, models::CannyPost)>> {
+    let company_spend: HashMap<String, f64> = client
+        .list_all_companies(None, None, None::<fn(usize)>)
+        .await?
+        .into_iter()
+        .filter_map(|company| Some((company.id, company.monthly_spend?)))
+        .collect();
+
+    let mut weighted: Vec<(f64, models::CannyPost)> = futures::stream::iter(posts)
+        .map(|post| {
+            let company_spend = &company_spend;
+            async move {
+                let votes = client.list_all_votes(&post.id).await?;
+                let mut companies: HashMap<&str, f64> = HashMap::new();
+                let voter_companies = votes
+                    .iter()
+                    .filter_map(|vote| vote.voter.as_ref())
+                    .flat_map(|voter| &voter.companies);
+                for company in voter_companies {
+                    let spend = company
+                        .monthly_spend
+                        .or_else(|| company_spend.get(&company.id).copied())
+                        .unwrap_or(0.0);
+                    companies.insert(&company.id, spend);
+                }
+                let weight: f64 = companies.values().sum();
+                Ok::<_, anyhow::Error>((weight, post))
+            }
+        })
+        .buffer_unordered(concurrency)
+        .try_collect()
+        .await?;
+
+    weighted.sort_by(|(a_weight, a), (b_weight, b)| {
+        b_weight.total_cmp(a_weight).then(b.score.cmp(&a.score))
+    });
+    Ok(weighted)
+}
+
+/// Print posts under a bold header per group, groups in alphabetical order
+/// and the highest-scored posts first within each
+fn print_grouped_posts(posts: Vec<models::CannyPost>, group_by: PostGroup) {
+    let mut groups: BTreeMap<String, Vec<models::CannyPost>> = BTreeMap::new();
+    for post in posts {
+        let key = match group_by {
+            PostGroup::Category => post.category.as_ref().map(|c| c.name.clone()),
+            PostGroup::Status => post.status.clone(),
+            PostGroup::Author => post.author.as_ref().map(|a| a.name.clone()),
+        };
+        let key = key.unwrap_or_else(|| match group_by {
+            PostGroup::Category => "Uncategorized".to_string(),
+            PostGroup::Status | PostGroup::Author => "unknown".to_string(),
+        });
+        groups.entry(key).or_default().push(post);
+    }
+
+    for (name, mut posts) in groups {
+        posts.sort_by_key(|post| std::cmp::Reverse(post.score));
+        println!("{} ({})", name.bold(), posts.len());
+        for post in &posts {
+            print_post_summary(post);
+        }
+    }
+}
+
+/// Print posts ranked by voter spend with their raw score alongside
+fn print_weighted_posts(posts: &[(f64, models::CannyPost)], json_output: bool) -> Result<()> {
+    if json_output {
+        let items = posts
+            .iter()
+            .map(|(weight, post)| {
+                let mut record = serde_json::to_value(post)?;
+                record["weightedSpend"] = serde_json::json!(weight);
+                Ok(record)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        return output::print_json_list(&items, false, None, None);
+    }
+
+    if posts.is_empty() {
+        println!("No posts found.");
+        return Ok(());
+    }
+
+    println!("{} ({} total)", "Posts by voter spend:".bold(), posts.len());
+    for (weight, post) in posts {
+        print_post_summary(post);
+        println!(
+            "  Voter spend: {} (score {})",
+            format!("${:.2}", weight).green(),
+            post.score
+        );
+    }
+    Ok(())
+}
+
+/// Posts past their ETA that aren't complete, paired with how long ago the
+/// ETA passed, most overdue first. ETAs that can't be parsed are reported
+/// as a warning and skipped.
+fn overdue_posts(
+    posts: Vec<models::CannyPost>,
+    now: DateTime<Utc>,
+) -> Vec<(chrono::Duration, models::CannyPost)> {
+    let mut unparsed = 0;
+    let mut overdue: Vec<(chrono::Duration, models::CannyPost)> = posts
+        .into_iter()
+        .filter(|post| {
+            !post
+                .status
+                .as_deref()
+                .is_some_and(|s| s.eq_ignore_ascii_case("complete"))
+        })
+        .filter_map(|post| {
+            let eta = post.eta.as_deref().filter(|e| !e.trim().is_empty())?;
+            let Some(deadline) = eta::eta_deadline(eta) else {
+                unparsed += 1;
+                return None;
+            };
+            (deadline < now).then(|| (now - deadline, post))
+        })
+        .collect();
+
+    if unparsed > 0 {
+        eprintln!(
+            "{} skipped {} posts with an ETA that couldn't be parsed",
+            "warning:".yellow(),
+            unparsed
+        );
+    }
+
+    overdue.sort_by(|(a, _), (b, _)| b.cmp(a));
+    overdue
+}
+
+/// Print overdue posts with how many days past their ETA each one is
+fn print_overdue_posts(
+    posts: &[(chrono::Duration, models::CannyPost)],
+    json_output: bool,
+) -> Result<()> {
+    if json_output {
+        let items = posts
+            .iter()
+            .map(|(overdue, post)| {
+                let mut record = serde_json::to_value(post)?;
+                record["overdueDays"] = serde_json::json!(overdue.num_days());
+                Ok(record)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        return output::print_json_list(&items, false, None, None);
+    }
+
+    if posts.is_empty() {
+        println!("No overdue posts.");
+        return Ok(());
+    }
+
+    println!("{} ({} posts)", "Overdue posts".bold(), posts.len());
+    println!("{}", "─".repeat(60).dimmed());
+    for (overdue, post) in posts {
+        println!(
+            "  {:>5}  {} {}",
+            format!("{}d", overdue.num_days()).red(),
+            post.title,
+            post.id.dimmed()
+        );
+        println!(
+            "         ETA {} · {}",
+            post.eta.as_deref().unwrap_or(""),
+            post.status.as_deref().unwrap_or("unknown")
+        );
+    }
+
+    Ok(())
+}
+
+/// Print candidate duplicates with their similarity, closest match first
+fn print_similar_posts(posts: &[(f64, models::CannyPost)], json_output: bool) -> Result<()> {
+    if json_output {
+        let items = posts
+            .iter()
+            .map(|(similarity, post)| {
+                let mut record = serde_json::to_value(post)?;
+                record["similarity"] = serde_json::json!((similarity * 100.0).round() / 100.0);
+                Ok(record)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        return output::print_json_list(&items, false, None, None);
+    }
+
+    if posts.is_empty() {
+        println!("No similar posts found.");
+        return Ok(());
+    }
+
+    println!("{} ({} found)", "Similar posts:".bold(), posts.len());
+    for (similarity, post) in posts {
+        print_post_summary(post);
+        println!("  Similarity: {}", format!("{:.0}%", similarity * 100.0).cyan());
+    }
+    Ok(())
+}
+
+/// Print status counts, vote and comment totals, and the top posts by score
+fn print_post_stats(posts: &[models::CannyPost], json_output: bool) -> Result<()> {
+    const STATUSES: [&str; 5] = ["open", "planned", "in progress", "complete", "closed"];
+
+    // Standard statuses always appear, in workflow order; custom ones follow
+    let mut by_status: Vec<(String, usize)> =
+        STATUSES.iter().map(|s| (s.to_string(), 0)).collect();
+    for post in posts {
+        let status = post.status.as_deref().unwrap_or("unknown").to_lowercase();
+        match by_status.iter_mut().find(|(s, _)| *s == status) {
+            Some((_, count)) => *count += 1,
+            None => by_status.push((status, 1)),
+        }
+    }
+
+    let total_votes: i64 = posts.iter().map(|p| p.score as i64).sum();
+    let total_comments: i64 = posts.iter().map(|p| p.comment_count as i64).sum();
+    let average_score = if posts.is_empty() {
+        0.0
+    } else {
+        total_votes as f64 / posts.len() as f64
+    };
+
+    let mut top: Vec<&models::CannyPost> = posts.iter().collect();
+    top.sort_by_key(|p| std::cmp::Reverse(p.score));
+    top.truncate(5);
+
+    if json_output {
+        let by_status: serde_json::Map<String, serde_json::Value> = by_status
+            .into_iter()
+            .map(|(status, count)| (status, count.into()))
+            .collect();
+        let top: Vec<serde_json::Value> = top
+            .iter()
+            .map(|p| serde_json::json!({"id": p.id, "title": p.title, "score": p.score}))
+            .collect();
+        let summary = serde_json::json!({
+            "posts": posts.len(),
+            "byStatus": by_status,
+            "totalVotes": total_votes,
+            "totalComments": total_comments,
+            "averageScore": average_score,
+            "topPosts": top,
+        });
+        println!("{}", output::json_string(&summary)?);
+        return Ok(());
+    }
+
+    println!("{} ({} posts)", "Board stats".bold(), posts.len());
+    println!("{}", "─".repeat(60).dimmed());
+    for (status, count) in &by_status {
+        println!("  {:<14} {}", status, count.to_string().cyan());
+    }
+    println!();
+    println!("Total votes: {}", total_votes.to_string().cyan());
+    println!("Total comments: {}", total_comments.to_string().cyan());
+    println!("Average score: {}", format!("{:.1}", average_score).cyan());
+
+    if !top.is_empty() {
+        println!("\n{}", "Top posts:".bold());
+        for post in top {
+            println!(
+                "  {:>5}  {} {}",
+                post.score.to_string().cyan(),
+                post.title,
+                post.id.dimmed()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn format_tags(tags: &[models::CannyTag]) -> String {
+    tags.iter()
+        .map(|t| t.name.magenta().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Whether a request failed because the object it names doesn't exist
+fn is_not_found(error: &anyhow::Error) -> bool {
+    matches!(error.downcast_ref::<CannyApiError>(), Some(CannyApiError::NotFound { .. }))
+}
+
+/// Look up the Canny ID of the user with the given email
+async fn user_id_for_email(client: &CannyClient, email: &str) -> Result<String> {
+    match client.lookup(client.get_user(None, Some(email))).await {
+        Ok(Some(user)) => Ok(user.id),
+        Ok(None) => anyhow::bail!("No user found with email {}", email),
+        Err(e) => Err(e.context(format!("No user found with email {}", email))),
+    }
+}
+
+/// Print a `--count-only` result, flagged when the listing stopped early
+fn print_count(client: &CannyClient, count: usize, json_output: bool) -> Result<()> {
+    output::print_count(count, !client.results_incomplete(), json_output)
+}
+
+/// Read the cursor saved by an earlier run with --cursor-file; a missing or
+/// empty file starts from the first page
+fn read_cursor_file(path: &Path) -> Result<Option<String>> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => Ok(Some(text.trim().to_string()).filter(|cursor| !cursor.is_empty())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => {
+            Err(e).with_context(|| format!("Failed to read cursor file {}", path.display()))
+        }
+    }
+}
+
+/// Run a cursor-paginated listing with --cursor-file: `list` starts from the
+/// cursor saved by the last run (or `cursor` without a file), and the cursor
+/// it returns to resume from is saved for the next one
+async fn list_with_cursor_file<F>(
+    cursor_file: Option<&Path>,
+    cursor: Option<String>,
+    list: F,
+) -> Result<()>
+where
+    F: AsyncFnOnce(Option<String>) -> Result<Option<String>>,
+{
+    let cursor = match cursor_file {
+        Some(path) => read_cursor_file(path)?,
+        None => cursor,
+    };
+    let resume_cursor = list(cursor).await?;
+    if let Some(path) = cursor_file {
+        write_cursor_file(path, resume_cursor.as_deref())?;
+    }
+    Ok(())
+}
+
+/// Save the cursor for the next run with --cursor-file. Without one (the
+/// API returned none), the file is left as it was.
+fn write_cursor_file(path: &Path, cursor: Option<&str>) -> Result<()> {
+    let Some(cursor) = cursor else {
+        return Ok(());
+    };
+    std::fs::write(path, format!("{}\n", cursor))
+        .with_context(|| format!("Failed to write cursor file {}", path.display()))
+}
+
+/// Resolve a long text argument: `@path` reads the file, `-` reads stdin,
+/// and anything else is used as given.
+fn resolve_text_arg(value: &str) -> Result<String> {
+    use std::io::Read;
+
+    if value == "-" {
+        let mut text = String::new();
+        std::io::stdin()
+            .read_to_string(&mut text)
+            .context("Failed to read from stdin")?;
+        Ok(text)
+    } else if let Some(path) = value.strip_prefix('@') {
+        std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path))
+    } else {
+        Ok(value.to_string())
+    }
+}
+
+/// Read an `--input-json` request body (inline, `@path` or `-`) and check it's
+/// an object with every required field before anything is sent
+fn read_input_json(value: &str, required: &[&str]) -> Result<serde_json::Value> {
+    let text = resolve_text_arg(value)?;
+    let body: serde_json::Value =
+        serde_json::from_str(&text).context("Failed to parse --input-json as JSON")?;
+    let Some(object) = body.as_object() else {
+        anyhow::bail!("--input-json must be a JSON object");
+    };
+
+    let missing: Vec<&str> = required
+        .iter()
+        .copied()
+        .filter(|field| object.get(*field).is_none_or(serde_json::Value::is_null))
+        .collect();
+    if !missing.is_empty() {
+        anyhow::bail!("--input-json is missing required fields: {}", missing.join(", "));
+    }
+
+    Ok(body)
+}
+
+/// Parse an ISO 8601 date (`2024-01-31`) or RFC 3339 timestamp
+/// (`2024-01-31T12:00:00Z`) as UTC
+fn parse_datetime(value: &str) -> std::result::Result<DateTime<Utc>, String> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Ok(dt.with_timezone(&Utc));
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map(|date| date.and_time(NaiveTime::MIN).and_utc())
+        .map_err(|_| format!("invalid date '{}': expected YYYY-MM-DD or RFC 3339", value))
+}
+
+/// Parse a similarity threshold between 0 and 1
+fn parse_similarity(value: &str) -> std::result::Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(threshold) if (0.0..=1.0).contains(&threshold) => Ok(threshold),
+        _ => Err(format!("invalid threshold '{}': expected a number from 0 to 1", value)),
+    }
+}
+
+/// Check that a date flag is an ISO 8601 date (`2024-01-31`) or RFC 3339
+/// timestamp, passing it through unchanged so the API sees what was typed
+fn parse_iso8601(value: &str) -> std::result::Result<String, String> {
+    parse_datetime(value).map(|_| value.to_string())
+}
+
+/// Combine posts fetched separately for several authors: drop duplicates
+/// and restore the requested order where it can be computed locally
+/// (relevance, trending and status-changed keep per-author order).
+fn merge_author_posts(posts: &mut Vec<models::CannyPost>, sort: &PostSort) {
+    let mut seen = HashSet::new();
+    posts.retain(|post| seen.insert(post.id.clone()));
+    match sort {
+        PostSort::Newest => posts.sort_by_key(|post| std::cmp::Reverse(post_created(post))),
+        PostSort::Oldest => posts.sort_by_key(post_created),
+        PostSort::Score => posts.sort_by_key(|post| std::cmp::Reverse(post.score)),
+        PostSort::Relevance | PostSort::StatusChanged | PostSort::Trending => {}
+    }
+}
+
+/// One line per voter: name, email and company names
+fn print_voter(voter: &models::CannyUser) {
+    let mut line = format!("  {}", voter.name.cyan());
+    if let Some(ref email) = voter.email {
+        line.push_str(&format!(" <{}>", email));
+    }
+    let companies: Vec<&str> = voter
+        .companies
+        .iter()
+        .map(|company| company.name.as_deref().unwrap_or(&company.id))
+        .collect();
+    if !companies.is_empty() {
+        line.push_str(&format!(" {}", companies.join(", ").dimmed()));
+    }
+    println!("{}", line);
+}
+
+/// Order posts merged from several boards, highest score or newest first
+fn sort_merged_posts(posts: &mut [models::CannyPost], sort: MergedPostSort) {
+    match sort {
+        MergedPostSort::Score => posts.sort_by_key(|post| std::cmp::Reverse(post.score)),
+        MergedPostSort::Created => posts.sort_by_key(|post| std::cmp::Reverse(post_created(post))),
+    }
+}
+
+/// A post's creation time, if present and parseable
+fn post_created(post: &models::CannyPost) -> Option<DateTime<Utc>> {
+    let created = post.created.as_deref()?;
+    DateTime::parse_from_rfc3339(created)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Whether both stdin and stdout are attached to a terminal
+fn is_interactive() -> bool {
+    use std::io::IsTerminal;
+
+    std::io::stdin().is_terminal() && std::io::stdout().is_terminal()
+}
+
+/// Prompt until the user enters a number between 1 and `count`; returns the 0-based index
+fn prompt_choice(prompt: &str, count: usize) -> Result<usize> {
+    use std::io::{self, Write};
+
+    loop {
+        print!("{} [1-{}]: ", prompt, count);
+        io::stdout().flush()?;
+        let mut answer = String::new();
+        if io::stdin().read_line(&mut answer)? == 0 {
+            anyhow::bail!("No selection made");
+        }
+        match answer.trim().parse::<usize>() {
+            Ok(n) if (1..=count).contains(&n) => return Ok(n - 1),
+            _ => println!("{}", "Please enter a number from the list.".red()),
+        }
+    }
+}
+
+/// Interactively choose a board (unless one is given) and then one of its
+/// most recent posts, returning the post ID
+async fn pick_post(client: &CannyClient, board_id: Option<&str>) -> Result<String> {
+    let board_id = match board_id {
+        Some(b) => b.to_string(),
+        None => {
+            let boards = client.list_boards().await?;
+            if boards.is_empty() {
+                anyhow::bail!("No boards found");
+            }
+            println!("{}", "Boards:".bold());
+            for (i, board) in boards.iter().enumerate() {
+                println!("  {:>3}) {}", i + 1, board.name);
+            }
+            let choice = prompt_choice("Select a board", boards.len())?;
+            boards[choice].id.clone()
+        }
+    };
+
+    let (response, _) = client
+        .list_posts(&board_id, Some(20), None, Some("newest"), None, None, None, None, None)
+        .await?;
+    if response.posts.is_empty() {
+        anyhow::bail!("No posts found on this board");
+    }
+    println!("\n{}", "Recent posts:".bold());
+    for (i, post) in response.posts.iter().enumerate() {
+        println!(
+            "  {:>3}) {} {}",
+            i + 1,
+            post.title,
+            format!("({} votes)", post.score).dimmed()
+        );
+    }
+    let choice = prompt_choice("Select a post", response.posts.len())?;
+    Ok(response.posts[choice].id.clone())
+}
+
+/// Ask a yes/no question on stdin, defaulting to no. Refuses to guess when
+/// stdin is not a terminal.
+fn confirm(prompt: &str) -> Result<bool> {
+    use std::io::{self, IsTerminal, Write};
+
+    if !io::stdin().is_terminal() {
+        anyhow::bail!("Confirmation required but stdin is not a terminal; pass --yes to proceed");
+    }
+
+    print!("{} [y/N]: ", prompt);
+    io::stdout().flush()?;
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer)?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+/// Read post IDs from a file, one per line, skipping blank lines and # comments
+fn read_post_ids(path: &Path) -> Result<Vec<String>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let ids: Vec<String> = contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(String::from)
+        .collect();
+
+    if ids.is_empty() {
+        anyhow::bail!("No post IDs found in {}", path.display());
+    }
+
+    Ok(ids)
+}
+
+async fn delete_posts_from_file(
+    client: &CannyClient,
+    path: &Path,
+    yes: bool,
+    json_output: bool,
+) -> Result<()> {
+    let ids = read_post_ids(path)?;
+
+    let dry_run = client.is_dry_run();
+    if !yes && !dry_run && !confirm(&format!("Permanently delete {} posts?", ids.len()))? {
+        println!("Aborted.");
+        return Ok(());
+    }
+
+    let mut results = Vec::with_capacity(ids.len());
+    for id in &ids {
+        let error = client.delete_post(id).await.err().map(|e| e.to_string());
+        if !json_output {
+            match error {
+                None => println!("{} {}", "✓".green(), id),
+                Some(ref e) => println!("{} {}: {}", "✗".red(), id, e),
+            }
+        }
+        results.push((id, error));
+    }
+
+    let failed = results.iter().filter(|(_, error)| error.is_some()).count();
+
+    if json_output {
+        let output: Vec<serde_json::Value> = results
+            .iter()
+            .map(|(id, error)| {
+                serde_json::json!({
+                    "id": id,
+                    "success": error.is_none(),
+                    "error": error,
+                })
+            })
+            .collect();
+        println!("{}", output::json_string(&output)?);
+    } else {
+        println!(
+            "\n{} {} of {} posts ({} failed).",
+            if dry_run { "Would delete" } else { "Deleted" },
+            results.len() - failed,
+            results.len(),
+            failed
+        );
+    }
+
+    if failed > 0 {
+        return Err(PartialFailure.into());
+    }
+
+    Ok(())
+}
+
+/// Status change applied to every post by `posts status-bulk`
+struct BulkStatusChange<'a> {
+    status: &'a str,
+    changer_id: &'a str,
+    notify: bool,
+    comment: Option<&'a str>,
+}
+
+async fn change_posts_status(
+    client: &CannyClient,
+    ids: &[String],
+    change: &BulkStatusChange<'_>,
+    yes: bool,
+    json_output: bool,
+) -> Result<()> {
+    if ids.is_empty() {
+        if json_output {
+            println!("[]");
+        } else {
+            println!("No matching posts.");
+        }
+        return Ok(());
+    }
+
+    let dry_run = client.is_dry_run();
+    let prompt = format!("Change the status of {} posts to '{}'?", ids.len(), change.status);
+    if !yes && !dry_run && !confirm(&prompt)? {
+        println!("Aborted.");
+        return Ok(());
+    }
+
+    let mut results = Vec::with_capacity(ids.len());
+    for id in ids {
+        let result = client
+            .change_post_status(
+                id,
+                change.changer_id,
+                change.status,
+                change.notify,
+                change.comment,
+                None,
+            )
+            .await;
+        let error = result.err().map(|e| e.to_string());
+        if !json_output {
+            match error {
+                None => println!("{} {}", "✓".green(), id),
+                Some(ref e) => println!("{} {}: {}", "✗".red(), id, e),
+            }
+        }
+        results.push((id, error));
+    }
+
+    let failed = results.iter().filter(|(_, error)| error.is_some()).count();
+
+    if json_output {
+        let output: Vec<serde_json::Value> = results
+            .iter()
+            .map(|(id, error)| {
+                serde_json::json!({
+                    "id": id,
+                    "success": error.is_none(),
+                    "error": error,
+                })
+            })
+            .collect();
+        println!("{}", output::json_string(&output)?);
+    } else {
+        println!(
+            "\n{} {} of {} posts to {} ({} failed).",
+            if dry_run { "Would change" } else { "Changed" },
+            results.len() - failed,
+            results.len(),
+            change.status.cyan(),
+            failed
+        );
+    }
+
+    if failed > 0 {
+        return Err(PartialFailure.into());
+    }
+
+    Ok(())
+}
+
+/// Read post records from an import file, paired with their line numbers.
+/// Records that fail to parse are kept as errors so they can be reported.
+fn read_import_records(
+    path: &Path,
+) -> Result<Vec<(u64, std::result::Result<models::PostImportRecord, String>)>> {
+    let is_csv = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+
+    if !is_csv {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        return Ok(contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                let record = serde_json::from_str(line).map_err(|e| format!("invalid JSON: {}", e));
+                (i as u64 + 1, record)
+            })
+            .collect());
+    }
+
+    let mut reader = csv::Reader::from_path(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let headers = reader.headers()?.clone();
+
+    let mut records = Vec::new();
+    for row in reader.records() {
+        let row = match row {
+            Ok(row) => row,
+            Err(e) => {
+                let line = e.position().map(|p| p.line()).unwrap_or(0);
+                records.push((line, Err(format!("invalid CSV: {}", e))));
+                continue;
+            }
+        };
+        let line = row.position().map(|p| p.line()).unwrap_or(0);
+
+        let field = |name: &str| {
+            headers
+                .iter()
+                .position(|h| h.trim() == name)
+                .and_then(|i| row.get(i))
+                .filter(|v| !v.trim().is_empty())
+                .map(str::to_string)
+        };
+
+        let custom_fields = match field("customFields").map(|v| serde_json::from_str(&v)) {
+            Some(Ok(value)) => Some(value),
+            Some(Err(e)) => {
+                records.push((line, Err(format!("invalid customFields JSON: {}", e))));
+                continue;
+            }
+            None => None,
+        };
+
+        records.push((
+            line,
+            Ok(models::PostImportRecord {
+                title: field("title"),
+                details: field("details"),
+                author_id: field("authorID"),
+                category_id: field("categoryID"),
+                created_at: field("createdAt"),
+                custom_fields,
+            }),
+        ));
+    }
+
+    Ok(records)
+}
+
+async fn import_posts(
+    client: &CannyClient,
+    board_id: &str,
+    path: &Path,
+    json_output: bool,
+) -> Result<()> {
+    let records = read_import_records(path)?;
+    if records.is_empty() {
+        anyhow::bail!("No posts found in {}", path.display());
+    }
+
+    let dry_run = client.is_dry_run();
+    let total = records.len();
+    let mut results = Vec::with_capacity(total);
+    let mut skipped = 0;
+    // Dry runs print each request, which the bar would draw over
+    let progress = if dry_run {
+        output::Progress::hidden()
+    } else {
+        output::Progress::bar("Importing posts", total)
+    };
+
+    for (index, (line, record)) in records.into_iter().enumerate() {
+        let record = record.and_then(|r| {
+            let title = r.title.clone().filter(|t| !t.trim().is_empty());
+            let author_id = r.author_id.clone().filter(|a| !a.trim().is_empty());
+            if let Some(created_at) = &r.created_at {
+                parse_iso8601(created_at).map_err(|e| format!("createdAt: {}", e))?;
+            }
+            match (title, author_id) {
+                (Some(title), Some(author_id)) => Ok((r, title, author_id)),
+                (None, _) => Err("missing title".to_string()),
+                (_, None) => Err("missing authorID".to_string()),
+            }
+        });
+
+        let (id, error) = match record {
+            Err(e) => {
+                skipped += 1;
+                (None, Some(e))
+            }
+            Ok((r, title, author_id)) => {
+                match client
+                    .create_post(
+                        board_id,
+                        &author_id,
+                        &title,
+                        r.details.as_deref(),
+                        r.category_id.as_deref(),
+                        None,
+                        r.custom_fields,
+                        None,
+                        None,
+                        None,
+                        None,
+                        r.created_at.as_deref(),
+                    )
+                    .await
+                {
+                    Ok(_) if dry_run => (None, None),
+                    Ok(id) => (Some(id), None),
+                    Err(e) => (None, Some(e.to_string())),
+                }
+            }
+        };
+
+        if !json_output {
+            let counter = format!("[{}/{}]", index + 1, total);
+            progress.println(&match (&id, &error) {
+                (_, Some(e)) => format!("{} {} line {}: {}", counter, "✗".red(), line, e),
+                (Some(id), None) => format!("{} {} line {}: {}", counter, "✓".green(), line, id),
+                (None, None) => format!("{} {} line {}: valid", counter, "✓".green(), line),
+            });
+        }
+        progress.inc();
+        results.push((line, id, error));
+    }
+    drop(progress);
+
+    let errors = results.iter().filter(|(_, _, error)| error.is_some()).count();
+    let failed = errors - skipped;
+
+    if json_output {
+        let output: Vec<serde_json::Value> = results
+            .iter()
+            .map(|(line, id, error)| {
+                serde_json::json!({
+                    "line": line,
+                    "id": id,
+                    "success": error.is_none(),
+                    "error": error,
+                })
+            })
+            .collect();
+        println!("{}", output::json_string(&output)?);
+    } else {
+        println!(
+            "\n{} {} of {} posts ({} skipped, {} failed).",
+            if dry_run { "Would import" } else { "Imported" },
+            total - errors,
+            total,
+            skipped,
+            failed
+        );
+    }
+
+    if errors > 0 {
+        return Err(PartialFailure.into());
+    }
+
+    Ok(())
+}
+
+/// Create or update every user in an NDJSON file. A user counts as updated
+/// when one with the same email already exists, and as created otherwise.
+async fn create_users_from_file(
     client: &CannyClient,
-    cmd: CategoriesCommands,
+    path: &Path,
+    concurrency: usize,
     json_output: bool,
 ) -> Result<()> {
-    match cmd {
-        CategoriesCommands::List {
-            board_id,
-            limit,
-            skip,
-        } => {
-            let response = client
-                .list_categories(&board_id, Some(limit), Some(skip))
-                .await?;
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let records: Vec<(usize, std::result::Result<models::UserImportRecord, String>)> = contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            let record = serde_json::from_str(line).map_err(|e| format!("invalid JSON: {}", e));
+            (i + 1, record)
+        })
+        .collect();
+    if records.is_empty() {
+        anyhow::bail!("No users found in {}", path.display());
+    }
 
-            if json_output {
-                println!("{}", serde_json::to_string_pretty(&response.categories)?);
-            } else {
-                if response.categories.is_empty() {
-                    println!("No categories found.");
-                } else {
-                    println!("{}", "Categories:".bold());
-                    for cat in &response.categories {
-                        println!(
-                            "  {} {} {}",
-                            cat.id.dimmed(),
-                            cat.name.cyan(),
-                            format!("({} posts)", cat.post_count.unwrap_or(0)).dimmed()
-                        );
-                    }
+    let dry_run = client.is_dry_run();
+    let total = records.len();
+
+    // Upsert several users at once; upserts are safe to resend, so the
+    // client retries 429s with backoff and a busy account slows the batch
+    // down instead of failing it
+    let mut upserts = futures::stream::iter(records)
+        .map(|(line, record)| async move {
+            let record = record.and_then(|r| {
+                let user_id = r.user_id.clone().filter(|u| !u.trim().is_empty());
+                let email = r.email.clone().filter(|e| !e.trim().is_empty());
+                match (user_id, email) {
+                    (Some(user_id), Some(email)) => Ok((r, user_id, email)),
+                    (None, _) => Err("missing userID".to_string()),
+                    (_, None) => Err("missing email".to_string()),
                 }
-            }
-        }
-
-        CategoriesCommands::Get { id } => {
-            let category = client.get_category(&id).await?;
-            if let Some(category) = category {
-                if json_output {
-                    println!("{}", serde_json::to_string_pretty(&category)?);
-                } else {
-                    print_category_detail(&category);
+            });
+
+            let (user_id, id, error) = match record {
+                Err(e) => (None, None, Some(e)),
+                Ok((r, user_id, email)) => {
+                    // The upsert keys on userID, so one request both creates
+                    // new users and updates existing ones
+                    let result = client
+                        .create_or_update_user(
+                            &user_id,
+                            &email,
+                            None,
+                            r.name.as_deref(),
+                            r.avatar_url.as_deref(),
+                            None,
+                            r.company_id.as_deref(),
+                            r.custom_fields,
+                        )
+                        .await;
+                    match result {
+                        Ok(_) if dry_run => (Some(user_id), None, None),
+                        Ok(id) => (Some(user_id), Some(id), None),
+                        Err(e) => (Some(user_id), None, Some(e.to_string())),
+                    }
                 }
-            } else {
-                eprintln!("{}", "Category not found.".red());
-                std::process::exit(1);
-            }
-        }
-
-        CategoriesCommands::Create {
-            board_id,
-            name,
-            parent_id,
-            subscribe_admins,
-        } => {
-            let id = client
-                .create_category(&board_id, &name, parent_id.as_deref(), subscribe_admins)
-                .await?;
-
-            if json_output {
-                println!(r#"{{"id": "{}"}}"#, id);
-            } else {
-                println!("{} Created category with ID: {}", "✓".green(), id.cyan());
-            }
+            };
+            (line, user_id, id, error)
+        })
+        .buffer_unordered(concurrency);
+
+    // Progress is printed as upserts finish; the summary is in file order.
+    // Dry runs print each request, which the bar would draw over.
+    let progress = if dry_run {
+        output::Progress::hidden()
+    } else {
+        output::Progress::bar("Syncing users", total)
+    };
+    let mut results = Vec::with_capacity(total);
+    while let Some(result) = upserts.next().await {
+        if !json_output {
+            let (line, user_id, _, error) = &result;
+            let counter = format!("[{}/{}]", results.len() + 1, total);
+            let user = user_id.as_deref().unwrap_or("-");
+            let (mark, outcome) = match error {
+                Some(e) => ("✗".red(), e.as_str()),
+                None if dry_run => ("✓".green(), "would be synced"),
+                None => ("✓".green(), "synced"),
+            };
+            progress.println(&format!(
+                "{} {} line {} ({}): {}",
+                counter, mark, line, user, outcome
+            ));
         }
+        progress.inc();
+        results.push(result);
+    }
+    drop(progress);
+    results.sort_by_key(|r| r.0);
+
+    let failed = results.iter().filter(|r| r.3.is_some()).count();
+    let synced = total - failed;
+
+    if json_output {
+        let output: Vec<serde_json::Value> = results
+            .iter()
+            .map(|(line, user_id, id, error)| {
+                serde_json::json!({
+                    "line": line,
+                    "userID": user_id,
+                    "id": id,
+                    "success": error.is_none(),
+                    "error": error,
+                })
+            })
+            .collect();
+        println!("{}", output::json_string(&output)?);
+    } else if dry_run {
+        println!("\nWould sync {} of {} users ({} failed).", synced, total, failed);
+    } else {
+        println!("\nSynced {} of {} users ({} failed).", synced, total, failed);
+    }
+    if !json_output && failed > 0 {
+        let failed_ids: Vec<String> = results
+            .iter()
+            .filter(|r| r.3.is_some())
+            .map(|(line, user_id, ..)| match user_id {
+                Some(user_id) => user_id.clone(),
+                None => format!("line {}", line),
+            })
+            .collect();
+        println!("Failed: {}", failed_ids.join(", "));
+    }
 
-        CategoriesCommands::Delete { id } => {
-            client.delete_category(&id).await?;
-
-            if json_output {
-                println!(r#"{{"success": true}}"#);
-            } else {
-                println!("{} Category deleted.", "✓".green());
-            }
-        }
+    if failed > 0 {
+        return Err(PartialFailure.into());
     }
 
     Ok(())
 }
 
-fn print_post_summary(post: &models::CannyPost) {
-    let status = post.status.as_deref().unwrap_or("unknown").to_uppercase();
-
-    let status_colored = match status.to_lowercase().as_str() {
-        "open" => status.yellow(),
-        "planned" | "in progress" => status.blue(),
-        "complete" => status.green(),
-        "closed" => status.red(),
-        _ => status.white(),
-    };
+fn print_posts_table(posts: &[models::CannyPost]) {
+    output::print_table(
+        &["ID", "Title", "Status", "Votes", "Comments", "Category"],
+        posts.iter().map(|post| {
+            vec![
+                post.id.clone(),
+                post.title.clone(),
+                post.status.clone().unwrap_or_default(),
+                post.score.to_string(),
+                post.comment_count.to_string(),
+                post.category.as_ref().map(|c| c.name.clone()).unwrap_or_default(),
+            ]
+        }),
+    );
+}
 
-    println!("\n{} {}", post.id.dimmed(), post.title.bold());
-    println!(
-        "  {} | {} votes | {} comments",
-        status_colored,
-        post.score.to_string().cyan(),
-        post.comment_count.to_string().cyan()
+fn print_posts_csv(posts: &[models::CannyPost]) {
+    output::print_csv(
+        &[
+            "id",
+            "title",
+            "status",
+            "score",
+            "commentCount",
+            "category",
+            "author",
+            "created",
+            "url",
+        ],
+        posts.iter().map(|post| {
+            vec![
+                post.id.clone(),
+                post.title.clone(),
+                post.status.clone().unwrap_or_default(),
+                post.score.to_string(),
+                post.comment_count.to_string(),
+                post.category.as_ref().map(|c| c.name.clone()).unwrap_or_default(),
+                post.author.as_ref().map(|a| a.name.clone()).unwrap_or_default(),
+                post.created.clone().unwrap_or_default(),
+                post.url.clone(),
+            ]
+        }),
     );
-    if let Some(ref cat) = post.category {
-        println!("  Category: {}", cat.name.magenta());
-    }
 }
 
 fn print_post_detail(post: &models::CannyPost) {
@@ -2111,10 +5506,30 @@ fn print_post_detail(post: &models::CannyPost) {
         println!("Author: {}", author.name);
     }
 
+    if let Some(ref by) = post.by {
+        println!("Created by: {}", by.name);
+    }
+
+    if let Some(ref owner) = post.owner {
+        println!("Owner: {}", owner.name);
+    }
+
+    if let Some(ref eta) = post.eta {
+        if post.eta_public == Some(false) {
+            println!("ETA: {} {}", eta, "(internal)".dimmed());
+        } else {
+            println!("ETA: {}", eta);
+        }
+    }
+
     if let Some(ref cat) = post.category {
         println!("Category: {}", cat.name.magenta());
     }
 
+    if !post.tags.is_empty() {
+        println!("Tags: {}", format_tags(&post.tags));
+    }
+
     if let Some(ref created) = post.created {
         println!("Created: {}", created.dimmed());
     }
@@ -2122,25 +5537,91 @@ fn print_post_detail(post: &models::CannyPost) {
     println!("URL: {}", post.url.underline());
     println!("ID: {}", post.id.dimmed());
 
+    let merged = post
+        .merge_history
+        .as_ref()
+        .and_then(|h| h.as_array())
+        .map_or(0, |h| h.len());
+    if merged > 0 {
+        println!("Merged: {} post{}", merged, if merged == 1 { "" } else { "s" });
+    }
+
+    let jira_issues: Vec<&serde_json::Value> = post
+        .jira
+        .as_ref()
+        .and_then(|j| j.get("linkedIssues"))
+        .and_then(|issues| issues.as_array())
+        .map(|issues| issues.iter().collect())
+        .unwrap_or_default();
+    if !jira_issues.is_empty() {
+        println!("\n{}", "Linked Jira:".bold());
+        for issue in jira_issues {
+            let key = issue.get("key").and_then(|k| k.as_str()).unwrap_or("?");
+            match issue.get("url").and_then(|u| u.as_str()) {
+                Some(url) => println!("  {} {}", key.cyan(), url.underline()),
+                None => println!("  {}", key.cyan()),
+            }
+        }
+    }
+
     if let Some(ref details) = post.details {
         if !details.is_empty() {
             println!("\n{}", "Description:".bold());
-            println!("{}", details);
+            println!("{}", output::markdown(details));
+        }
+    }
+}
+
+/// Print comments as threads: each top-level comment followed by its replies,
+/// indented by depth. Replies whose parent isn't in the list are shown at the
+/// top level.
+fn print_comment_tree(comments: &[models::CannyComment]) {
+    let ids: HashSet<&str> = comments.iter().map(|c| c.id.as_str()).collect();
+    let mut children: HashMap<&str, Vec<&models::CannyComment>> = HashMap::new();
+    let mut roots = Vec::new();
+    for comment in comments {
+        match comment.parent_id.as_deref() {
+            Some(parent) if ids.contains(parent) => {
+                children.entry(parent).or_default().push(comment)
+            }
+            _ => roots.push(comment),
+        }
+    }
+
+    fn print_thread<'a>(
+        comment: &'a models::CannyComment,
+        depth: usize,
+        children: &HashMap<&str, Vec<&'a models::CannyComment>>,
+        printed: &mut HashSet<&'a str>,
+    ) {
+        // Guard against malformed data where replies form a cycle
+        if !printed.insert(comment.id.as_str()) {
+            return;
         }
+        print_comment(comment, depth);
+        for reply in children.get(comment.id.as_str()).into_iter().flatten() {
+            print_thread(reply, depth + 1, children, printed);
+        }
+    }
+
+    let mut printed = HashSet::new();
+    for root in roots {
+        print_thread(root, 0, &children, &mut printed);
     }
 }
 
-fn print_comment(comment: &models::CannyComment) {
+fn print_comment(comment: &models::CannyComment, depth: usize) {
     let author_name = comment
         .author
         .as_ref()
         .map(|a| a.name.as_str())
         .unwrap_or("Unknown");
 
-    let prefix = if comment.parent_id.is_some() {
-        "  ↳ "
+    let indent = "  ".repeat(depth);
+    let (prefix, continuation) = if depth > 0 {
+        (format!("{}↳ ", indent), format!("{}  ", indent))
     } else {
-        ""
+        (String::new(), String::new())
     };
 
     let pinned = if comment.pinned.unwrap_or(false) {
@@ -2148,16 +5629,24 @@ fn print_comment(comment: &models::CannyComment) {
     } else {
         String::new()
     };
+    let internal = if comment.internal.unwrap_or(false) {
+        " [INTERNAL]".magenta().to_string()
+    } else {
+        String::new()
+    };
 
     println!(
-        "\n{}{} {}{}",
+        "\n{}{} {}{}{}",
         prefix,
         author_name.cyan(),
         comment.created.dimmed(),
-        pinned
+        pinned,
+        internal
     );
-    println!("{}{}", prefix, comment.value);
-    println!("{}{}", prefix, format!("ID: {}", comment.id).dimmed());
+    for line in comment.value.lines() {
+        println!("{}{}", continuation, line);
+    }
+    println!("{}{}", continuation, format!("ID: {}", comment.id).dimmed());
 }
 
 fn print_comment_detail(comment: &models::CannyComment) {
@@ -2187,6 +5676,10 @@ fn print_comment_detail(comment: &models::CannyComment) {
         println!("Pinned: {}", "Yes".yellow());
     }
 
+    if comment.internal.unwrap_or(false) {
+        println!("Internal: {}", "Yes".magenta());
+    }
+
     println!("\n{}", "Content:".bold());
     println!("{}", comment.value);
 }
@@ -2206,37 +5699,62 @@ fn print_category_detail(category: &models::CannyCategory) {
     }
 }
 
-async fn handle_users(client: &CannyClient, cmd: UsersCommands, json_output: bool) -> Result<()> {
-    use std::io::Write;
+async fn handle_users(client: &CannyClient, cmd: UsersCommands, format: OutputFormat) -> Result<()> {
+    let json_output = format.is_json();
 
     match cmd {
-        UsersCommands::List => {
-            let users = if json_output {
-                client.list_users(None::<fn(usize)>).await?
-            } else {
+        UsersCommands::List {
+            company_id,
+            search,
+            limit,
+            count_only,
+        } => {
+            let company_id = company_id.as_deref();
+            let search = search.as_deref();
+            if count_only {
+                let progress = output::Progress::spinner("Counting users...");
+                let mut counted = 0;
+                let count = client
+                    .for_each_user_page(company_id, search, limit, |users| {
+                        counted += users.len();
+                        progress.set(counted);
+                        Ok(())
+                    })
+                    .await?;
+                drop(progress);
+                return print_count(client, count, json_output);
+            }
+            if format == OutputFormat::Ndjson {
                 client
-                    .list_users(Some(|count: usize| {
-                        print!("\rFetching users... {}", count);
-                        let _ = std::io::stdout().flush();
-                    }))
-                    .await?
-            };
-
-            // Clear the progress line
-            if !json_output {
-                print!("\r\x1b[K"); // Clear line
-                let _ = std::io::stdout().flush();
+                    .for_each_user_page(company_id, search, limit, |users| {
+                        users.iter().try_for_each(output::print_json_line)
+                    })
+                    .await?;
+                return Ok(());
             }
 
+            let progress = match limit {
+                Some(max) => output::Progress::bar("Fetching users...", max),
+                None => output::Progress::spinner("Fetching users..."),
+            };
+            let users = client
+                .list_users(company_id, search, limit, Some(|count: usize| progress.set(count)))
+                .await?;
+            drop(progress);
+
             if json_output {
-                println!("{}", serde_json::to_string_pretty(&users)?);
+                output::print_json_list(&users, false, None, None)?;
             } else {
                 if users.is_empty() {
                     println!("No users found.");
                 } else {
                     println!("{} ({} total)", "Users:".bold(), users.len());
-                    for user in &users {
-                        print_user(user);
+                    if format == OutputFormat::Table {
+                        print_users_table(&users);
+                    } else {
+                        for user in &users {
+                            print_user(user);
+                        }
                     }
                 }
             }
@@ -2250,13 +5768,12 @@ async fn handle_users(client: &CannyClient, cmd: UsersCommands, json_output: boo
             let user = client.get_user(id.as_deref(), email.as_deref()).await?;
             if let Some(user) = user {
                 if json_output {
-                    println!("{}", serde_json::to_string_pretty(&user)?);
+                    output::print_json(&user)?;
                 } else {
                     print_user_detail(&user);
                 }
             } else {
-                eprintln!("{}", "User not found.".red());
-                std::process::exit(1);
+                return Err(NotFound("User").into());
             }
         }
 
@@ -2268,30 +5785,49 @@ async fn handle_users(client: &CannyClient, cmd: UsersCommands, json_output: boo
             avatar_url,
             company_id,
             custom_fields,
+            from_file,
+            concurrency,
+            input_json,
         } => {
-            // Parse custom fields from JSON string if provided
-            let custom_fields_value = match custom_fields {
-                Some(ref json_str) => {
-                    Some(serde_json::from_str(json_str).context("Failed to parse custom-fields as JSON")?)
-                }
-                None => None,
-            };
+            if let Some(path) = from_file {
+                return create_users_from_file(client, &path, concurrency as usize, json_output)
+                    .await;
+            }
+            let id = if let Some(input) = input_json {
+                let body = read_input_json(&input, &["userID", "email"])?;
+                client.create_from_body("users/create_or_update", body).await?
+            } else {
+                let user_id = user_id
+                    .context("Either --user-id, --from-file or --input-json must be provided")?;
+                let email = email
+                    .context("Either --email, --from-file or --input-json must be provided")?;
+
+                // Parse custom fields from JSON string if provided
+                let custom_fields_value = match custom_fields {
+                    Some(ref json_str) => {
+                        Some(serde_json::from_str(json_str).context("Failed to parse custom-fields as JSON")?)
+                    }
+                    None => None,
+                };
 
-            let id = client
-                .create_or_update_user(
-                    &user_id,
-                    &email,
-                    canny_id.as_deref(),
-                    name.as_deref(),
-                    avatar_url.as_deref(),
-                    None,
-                    company_id.as_deref(),
-                    custom_fields_value,
-                )
-                .await?;
+                client
+                    .create_or_update_user(
+                        &user_id,
+                        &email,
+                        canny_id.as_deref(),
+                        name.as_deref(),
+                        avatar_url.as_deref(),
+                        None,
+                        company_id.as_deref(),
+                        custom_fields_value,
+                    )
+                    .await?
+            };
 
             if json_output {
                 println!(r#"{{"id": "{}"}}"#, id);
+            } else if output::is_quiet() {
+                println!("{}", id);
             } else {
                 println!(
                     "{} Created/updated user with ID: {}",
@@ -2306,7 +5842,7 @@ async fn handle_users(client: &CannyClient, cmd: UsersCommands, json_output: boo
 
             if json_output {
                 println!(r#"{{"success": true}}"#);
-            } else {
+            } else if !output::is_quiet() {
                 println!("{} User deleted.", "✓".green());
             }
         }
@@ -2325,13 +5861,26 @@ async fn handle_users(client: &CannyClient, cmd: UsersCommands, json_output: boo
                 .await?;
             if let Some(user) = user {
                 if json_output {
-                    println!("{}", serde_json::to_string_pretty(&user)?);
+                    output::print_json(&user)?;
                 } else {
                     print_user_detail(&user);
                 }
             } else {
-                eprintln!("{}", "User not found.".red());
-                std::process::exit(1);
+                return Err(NotFound("User").into());
+            }
+        }
+
+        UsersCommands::AddToCompany {
+            user_id,
+            company_id,
+        } => {
+            let company = CannyClient::company_record(&company_id, None, None, None, None);
+            client.add_user_to_company(&user_id, company).await?;
+
+            if json_output {
+                println!(r#"{{"success": true}}"#);
+            } else if !output::is_quiet() {
+                println!("{} User added to company.", "✓".green());
             }
         }
 
@@ -2345,7 +5894,7 @@ async fn handle_users(client: &CannyClient, cmd: UsersCommands, json_output: boo
 
             if json_output {
                 println!(r#"{{"success": true}}"#);
-            } else {
+            } else if !output::is_quiet() {
                 println!("{} User removed from company.", "✓".green());
             }
         }
@@ -2354,6 +5903,21 @@ async fn handle_users(client: &CannyClient, cmd: UsersCommands, json_output: boo
     Ok(())
 }
 
+fn print_users_table(users: &[models::CannyUserFull]) {
+    output::print_table(
+        &["ID", "Name", "Email", "Admin", "Last Activity"],
+        users.iter().map(|user| {
+            vec![
+                user.id.clone(),
+                user.name.clone().unwrap_or_default(),
+                user.email.clone().unwrap_or_default(),
+                if user.is_admin.unwrap_or(false) { "yes" } else { "no" }.to_string(),
+                user.last_activity.clone().unwrap_or_default(),
+            ]
+        }),
+    );
+}
+
 fn print_user(user: &models::CannyUserFull) {
     let name = user.name.as_deref().unwrap_or("(no name)");
     let email = user.email.as_deref().unwrap_or("");
@@ -2402,38 +5966,61 @@ fn print_user_detail(user: &models::CannyUserFull) {
     if let Some(ref user_id) = user.user_id {
         println!("User ID: {}", user_id.dimmed());
     }
+
+    if !user.companies.is_empty() {
+        let companies: Vec<String> = user
+            .companies
+            .iter()
+            .map(|c| c.name.clone().unwrap_or_else(|| c.id.clone()))
+            .collect();
+        println!("Companies: {}", companies.join(", ").magenta());
+    }
+
+    if let Some(ref custom_fields) = user.custom_fields {
+        if !custom_fields.is_null() {
+            println!(
+                "Custom Fields: {}",
+                serde_json::to_string_pretty(custom_fields).unwrap_or_default()
+            );
+        }
+    }
 }
 
-async fn handle_boards(client: &CannyClient, cmd: BoardsCommands, json_output: bool) -> Result<()> {
+async fn handle_boards(client: &CannyClient, cmd: BoardsCommands, format: OutputFormat) -> Result<()> {
+    let json_output = format.is_json();
+
     match cmd {
         BoardsCommands::List => {
             let boards = client.list_boards().await?;
 
             if json_output {
-                println!("{}", serde_json::to_string_pretty(&boards)?);
+                output::print_json_list(&boards, false, None, None)?;
             } else {
                 if boards.is_empty() {
                     println!("No boards found.");
                 } else {
                     println!("{} ({} total)", "Boards:".bold(), boards.len());
-                    for board in &boards {
-                        print_board(board);
+                    if format == OutputFormat::Table {
+                        print_boards_table(&boards);
+                    } else {
+                        for board in &boards {
+                            print_board(board, false);
+                        }
                     }
                 }
             }
         }
 
-        BoardsCommands::Get { id } => {
+        BoardsCommands::Get { id, show_token } => {
             let board = client.get_board(&id).await?;
             if let Some(board) = board {
                 if json_output {
-                    println!("{}", serde_json::to_string_pretty(&board)?);
+                    output::print_json(&board)?;
                 } else {
-                    print_board(&board);
+                    print_board(&board, show_token);
                 }
             } else {
-                eprintln!("{}", "Board not found.".red());
-                std::process::exit(1);
+                return Err(NotFound("Board").into());
             }
         }
 
@@ -2442,18 +6029,135 @@ async fn handle_boards(client: &CannyClient, cmd: BoardsCommands, json_output: b
 
             if json_output {
                 println!(r#"{{"id": "{}"}}"#, id);
+            } else if output::is_quiet() {
+                println!("{}", id);
             } else {
                 println!("{} Created board with ID: {}", "✓".green(), id.cyan());
             }
         }
 
+        BoardsCommands::Update {
+            id,
+            name,
+            is_private,
+            private_comments,
+        } => {
+            if name.is_none() && is_private.is_none() && private_comments.is_none() {
+                anyhow::bail!(
+                    "Nothing to update: provide --name, --is-private or --private-comments"
+                );
+            }
+
+            client
+                .update_board(&id, name.as_deref(), is_private, private_comments)
+                .await?;
+
+            if json_output {
+                println!(r#"{{"success": true}}"#);
+            } else if !output::is_quiet() {
+                println!("{} Board updated.", "✓".green());
+            }
+        }
+
         BoardsCommands::Delete { id } => {
             client.delete_board(&id).await?;
 
             if json_output {
                 println!(r#"{{"success": true}}"#);
+            } else if !output::is_quiet() {
+                println!("{} Board deleted.", "✓".green());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn print_boards_table(boards: &[models::CannyBoard]) {
+    output::print_table(
+        &["ID", "Name", "Posts", "Private"],
+        boards.iter().map(|board| {
+            vec![
+                board.id.clone(),
+                board.name.clone(),
+                board.post_count.unwrap_or(0).to_string(),
+                if board.is_private.unwrap_or(false) { "yes" } else { "no" }.to_string(),
+            ]
+        }),
+    );
+}
+
+/// Print a board. Its token, which identifies users in the widget, is only
+/// shown with `show_token`.
+fn print_board(board: &models::CannyBoard, show_token: bool) {
+    let private_badge = if board.is_private.unwrap_or(false) {
+        " [PRIVATE]".yellow().to_string()
+    } else {
+        String::new()
+    };
+
+    let post_count = board.post_count.unwrap_or(0);
+
+    println!(
+        "\n  {} {}{}",
+        board.id.dimmed(),
+        board.name.cyan(),
+        private_badge
+    );
+    println!("    Posts: {}", post_count);
+    if let Some(private_comments) = board.private_comments {
+        println!("    Private comments: {}", if private_comments { "yes" } else { "no" });
+    }
+    if let Some(ref created) = board.created {
+        println!("    Created: {}", created.dimmed());
+    }
+    if let Some(ref url) = board.url {
+        println!("    URL: {}", url.underline());
+    }
+    if let Some(ref token) = board.token {
+        if show_token {
+            println!("    Token: {}", token.yellow());
+        } else {
+            println!("    Token: {}", "hidden (use --show-token to reveal)".dimmed());
+        }
+    }
+}
+
+async fn handle_segments(
+    client: &CannyClient,
+    cmd: SegmentsCommands,
+    json_output: bool,
+) -> Result<()> {
+    match cmd {
+        SegmentsCommands::List => {
+            let segments = client.list_segments().await?;
+
+            if json_output {
+                output::print_json_list(&segments, false, None, None)?;
+            } else if segments.is_empty() {
+                println!("No segments found.");
+            } else {
+                println!("{} ({} total)", "Segments:".bold(), segments.len());
+                for segment in &segments {
+                    print_segment(segment);
+                }
+            }
+        }
+
+        SegmentsCommands::Get { id, url_name } => {
+            // Segments are few, so they're looked up in the full list
+            let segment = client.list_segments().await?.into_iter().find(|segment| match id {
+                Some(ref id) => segment.id == *id,
+                None => segment.url_name == url_name,
+            });
+            if let Some(segment) = segment {
+                if json_output {
+                    output::print_json(&segment)?;
+                } else {
+                    print_segment(&segment);
+                }
             } else {
-                println!("{} Board deleted.", "✓".green());
+                return Err(NotFound("Segment").into());
             }
         }
     }
@@ -2461,45 +6165,61 @@ async fn handle_boards(client: &CannyClient, cmd: BoardsCommands, json_output: b
     Ok(())
 }
 
-fn print_board(board: &models::CannyBoard) {
-    let private_badge = if board.is_private.unwrap_or(false) {
-        " [PRIVATE]".yellow().to_string()
-    } else {
-        String::new()
-    };
-
-    let post_count = board.post_count.unwrap_or(0);
-
-    println!(
-        "\n  {} {}{}",
-        board.id.dimmed(),
-        board.name.cyan(),
-        private_badge
-    );
-    println!("    Posts: {}", post_count);
-    if let Some(ref url) = board.url {
-        println!("    URL: {}", url.underline());
+fn print_segment(segment: &models::CannySegment) {
+    println!("\n  {} {}", segment.id.dimmed(), segment.name.cyan());
+    if let Some(ref url_name) = segment.url_name {
+        println!("    URL name: {}", url_name);
     }
 }
 
-async fn handle_tags(client: &CannyClient, cmd: TagsCommands, json_output: bool) -> Result<()> {
+async fn handle_tags(client: &CannyClient, cmd: TagsCommands, format: OutputFormat) -> Result<()> {
+    let json_output = format.is_json();
+
     match cmd {
         TagsCommands::List {
             board_id,
             limit,
             skip,
+            all,
+            count_only,
         } => {
-            let response = client.list_tags(&board_id, Some(limit), Some(skip)).await?;
+            if all && format == OutputFormat::Ndjson && !count_only {
+                return client
+                    .for_each_tag_page(&board_id, |tags| {
+                        tags.iter().try_for_each(output::print_json_line)
+                    })
+                    .await;
+            }
 
-            if json_output {
-                println!("{}", serde_json::to_string_pretty(&response.tags)?);
+            let response = if all {
+                models::TagsListResponse {
+                    has_more: false,
+                    tags: client.list_all_tags(&board_id).await?,
+                }
+            } else {
+                client.list_tags(&board_id, Some(limit), Some(skip)).await?.0
+            };
+
+            if count_only {
+                print_count(client, response.tags.len(), json_output)?;
+            } else if json_output {
+                output::print_json_list(
+                    &response.tags,
+                    response.has_more,
+                    None,
+                    Some(skip + limit),
+                )?;
             } else {
                 if response.tags.is_empty() {
                     println!("No tags found.");
                 } else {
                     println!("{}", "Tags:".bold());
-                    for tag in &response.tags {
-                        print_tag(tag);
+                    if format == OutputFormat::Table {
+                        print_tags_table(&response.tags);
+                    } else {
+                        for tag in &response.tags {
+                            print_tag(tag);
+                        }
                     }
                     if response.has_more {
                         println!(
@@ -2512,17 +6232,27 @@ async fn handle_tags(client: &CannyClient, cmd: TagsCommands, json_output: bool)
             }
         }
 
+        TagsCommands::Report { board_id } => {
+            let mut tags = client.list_all_tags(&board_id).await?;
+            tags.sort_by(|a, b| {
+                a.post_count
+                    .unwrap_or(0)
+                    .cmp(&b.post_count.unwrap_or(0))
+                    .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+            });
+            print_tag_report(&tags, json_output)?;
+        }
+
         TagsCommands::Get { id } => {
             let tag = client.get_tag(&id).await?;
             if let Some(tag) = tag {
                 if json_output {
-                    println!("{}", serde_json::to_string_pretty(&tag)?);
+                    output::print_json(&tag)?;
                 } else {
                     print_tag_detail(&tag);
                 }
             } else {
-                eprintln!("{}", "Tag not found.".red());
-                std::process::exit(1);
+                return Err(NotFound("Tag").into());
             }
         }
 
@@ -2531,17 +6261,29 @@ async fn handle_tags(client: &CannyClient, cmd: TagsCommands, json_output: bool)
 
             if json_output {
                 println!(r#"{{"id": "{}"}}"#, id);
+            } else if output::is_quiet() {
+                println!("{}", id);
             } else {
                 println!("{} Created tag with ID: {}", "✓".green(), id.cyan());
             }
         }
 
+        TagsCommands::Update { id, name, board_id } => {
+            client.update_tag(&id, &name, board_id.as_deref()).await?;
+
+            if json_output {
+                println!(r#"{{"success": true}}"#);
+            } else if !output::is_quiet() {
+                println!("{} Tag renamed to {}.", "✓".green(), name.magenta());
+            }
+        }
+
         TagsCommands::Delete { id } => {
             client.delete_tag(&id).await?;
 
             if json_output {
                 println!(r#"{{"success": true}}"#);
-            } else {
+            } else if !output::is_quiet() {
                 println!("{} Tag deleted.", "✓".green());
             }
         }
@@ -2550,6 +6292,19 @@ async fn handle_tags(client: &CannyClient, cmd: TagsCommands, json_output: bool)
     Ok(())
 }
 
+fn print_tags_table(tags: &[models::CannyTag]) {
+    output::print_table(
+        &["ID", "Name", "Posts"],
+        tags.iter().map(|tag| {
+            vec![
+                tag.id.clone(),
+                tag.name.clone(),
+                tag.post_count.unwrap_or(0).to_string(),
+            ]
+        }),
+    );
+}
+
 fn print_tag(tag: &models::CannyTag) {
     println!(
         "  {} {} {}",
@@ -2559,6 +6314,59 @@ fn print_tag(tag: &models::CannyTag) {
     );
 }
 
+/// Print tags with their post counts, flagging unused ones
+fn print_tag_report(tags: &[models::CannyTag], json_output: bool) -> Result<()> {
+    let unused = tags.iter().filter(|t| t.post_count.unwrap_or(0) == 0).count();
+
+    if json_output {
+        let tags: Vec<serde_json::Value> = tags
+            .iter()
+            .map(|t| {
+                let post_count = t.post_count.unwrap_or(0);
+                serde_json::json!({
+                    "id": t.id,
+                    "name": t.name,
+                    "postCount": post_count,
+                    "unused": post_count == 0,
+                })
+            })
+            .collect();
+        return output::print_json(&tags);
+    }
+
+    if tags.is_empty() {
+        println!("No tags found.");
+        return Ok(());
+    }
+
+    println!("{} ({} total)", "Tag usage:".bold(), tags.len());
+    for tag in tags {
+        let post_count = tag.post_count.unwrap_or(0);
+        let count = format!("{:>6}", post_count);
+        if post_count == 0 {
+            println!(
+                "  {} {} {} {}",
+                count.yellow(),
+                tag.name,
+                tag.id.dimmed(),
+                "(unused)".yellow()
+            );
+        } else {
+            println!("  {} {} {}", count.cyan(), tag.name, tag.id.dimmed());
+        }
+    }
+
+    if unused > 0 {
+        println!(
+            "\n{} unused tag{} (candidates for deletion).",
+            unused,
+            if unused == 1 { "" } else { "s" }
+        );
+    }
+
+    Ok(())
+}
+
 fn print_tag_detail(tag: &models::CannyTag) {
     println!("\n{}", tag.name.bold());
     println!("{}", "─".repeat(60).dimmed());
@@ -2582,43 +6390,144 @@ fn print_tag_detail(tag: &models::CannyTag) {
 async fn handle_companies(
     client: &CannyClient,
     cmd: CompaniesCommands,
-    json_output: bool,
+    format: OutputFormat,
 ) -> Result<()> {
+    let json_output = format.is_json();
+
     match cmd {
         CompaniesCommands::List {
             limit,
             cursor,
             search,
             segment,
+            min_monthly_spend,
+            sort,
+            all,
+            count_only,
+            cursor_file,
         } => {
-            let response = client
-                .list_companies(Some(limit), cursor.as_deref(), search.as_deref(), segment.as_deref())
-                .await?;
+            let (search, segment) = (search.as_deref(), segment.as_deref());
+            let meets_min_spend = |c: &models::CannyCompany| {
+                min_monthly_spend.is_none_or(|min| c.monthly_spend.is_some_and(|s| s >= min))
+            };
+            list_with_cursor_file(cursor_file.as_deref(), cursor, async |cursor| {
+                // Without --sort, which needs every company first, pages are
+                // printed as they arrive
+                if all && sort.is_none() && format == OutputFormat::Ndjson && !count_only {
+                    let resume_cursor = client
+                        .for_each_company_page(search, segment, cursor, |companies| {
+                            companies
+                                .iter()
+                                .filter(|c| meets_min_spend(c))
+                                .try_for_each(output::print_json_line)
+                        })
+                        .await?;
+                    return Ok(resume_cursor);
+                }
 
-            if json_output {
-                println!("{}", serde_json::to_string_pretty(&response.companies)?);
-            } else {
-                if response.companies.is_empty() {
-                    println!("No companies found.");
+                let (mut response, resume_cursor) = if all {
+                    let progress = output::Progress::spinner("Fetching companies...");
+                    let mut companies = Vec::new();
+                    let resume_cursor = client
+                        .for_each_company_page(search, segment, cursor, |page| {
+                            companies.extend(page);
+                            progress.set(companies.len());
+                            Ok(())
+                        })
+                        .await?;
+                    drop(progress);
+
+                    let response = models::CompaniesListResponse {
+                        has_next_page: Some(false),
+                        cursor: None,
+                        companies,
+                    };
+                    (response, resume_cursor)
                 } else {
-                    println!(
-                        "{} ({} returned)",
-                        "Companies:".bold(),
-                        response.companies.len()
-                    );
-                    for company in &response.companies {
-                        print_company(company);
-                    }
-                    if response.has_next_page.unwrap_or(false) {
-                        if let Some(ref next_cursor) = response.cursor {
-                            println!(
-                                "\n{} Use --cursor {} to see more.",
-                                "More companies available.".dimmed(),
-                                next_cursor
-                            );
+                    let response = client
+                        .list_companies(Some(limit), cursor.as_deref(), search, segment)
+                        .await?;
+                    let resume_cursor = response.cursor.clone().or(cursor);
+                    (response, resume_cursor)
+                };
+
+                response.companies.retain(meets_min_spend);
+                if let Some(sort) = sort {
+                    sort_companies(&mut response.companies, sort);
+                }
+
+                if count_only {
+                    print_count(client, response.companies.len(), json_output)?;
+                } else if format == OutputFormat::Csv {
+                    print_companies_csv(&response.companies);
+                } else if json_output {
+                    output::print_json_list(
+                        &response.companies,
+                        response.has_next_page.unwrap_or(false),
+                        response.cursor.as_deref(),
+                        None,
+                    )?;
+                } else {
+                    if response.companies.is_empty() {
+                        println!("No companies found.");
+                    } else {
+                        println!(
+                            "{} ({} returned)",
+                            "Companies:".bold(),
+                            response.companies.len()
+                        );
+                        if format == OutputFormat::Table {
+                            print_companies_table(&response.companies);
+                        } else {
+                            for company in &response.companies {
+                                print_company(company);
+                            }
+                        }
+                        if response.has_next_page.unwrap_or(false) {
+                            if let Some(ref next_cursor) = response.cursor {
+                                println!(
+                                    "\n{} Use --cursor {} to see more.",
+                                    "More companies available.".dimmed(),
+                                    next_cursor
+                                );
+                            }
                         }
                     }
                 }
+                Ok(resume_cursor)
+            })
+            .await?;
+        }
+
+        CompaniesCommands::Create {
+            id,
+            user_id,
+            name,
+            monthly_spend,
+            custom_fields,
+            created,
+        } => {
+            let custom_fields_value = if let Some(cf_str) = custom_fields {
+                Some(serde_json::from_str(&cf_str).context("Invalid JSON for custom_fields")?)
+            } else {
+                None
+            };
+
+            let company = CannyClient::company_record(
+                &id,
+                Some(&name),
+                monthly_spend,
+                custom_fields_value,
+                created.as_deref(),
+            );
+            client.add_user_to_company(&user_id, company).await?;
+
+            if json_output {
+                println!(r#"{{"id": "{}"}}"#, id);
+            } else if output::is_quiet() {
+                println!("{}", id);
+            } else {
+                println!("{} Created company with ID: {}", "✓".green(), id.cyan());
             }
         }
 
@@ -2642,7 +6551,7 @@ async fn handle_companies(
 
             if json_output {
                 println!(r#"{{"success": true}}"#);
-            } else {
+            } else if !output::is_quiet() {
                 println!("{} Company updated.", "✓".green());
             }
         }
@@ -2651,13 +6560,12 @@ async fn handle_companies(
             let company = client.get_company(&id).await?;
             if let Some(company) = company {
                 if json_output {
-                    println!("{}", serde_json::to_string_pretty(&company)?);
+                    output::print_json(&company)?;
                 } else {
                     print_company_detail(&company);
                 }
             } else {
-                eprintln!("{}", "Company not found.".red());
-                std::process::exit(1);
+                return Err(NotFound("Company").into());
             }
         }
 
@@ -2666,7 +6574,7 @@ async fn handle_companies(
 
             if json_output {
                 println!(r#"{{"success": true}}"#);
-            } else {
+            } else if !output::is_quiet() {
                 println!("{} Company deleted.", "✓".green());
             }
         }
@@ -2689,6 +6597,63 @@ fn print_company(company: &models::CannyCompany) {
     }
 }
 
+/// Order present values first, comparing them with `cmp`, and missing ones last
+fn missing_last<T>(a: Option<T>, b: Option<T>, cmp: impl Fn(T, T) -> Ordering) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => cmp(a, b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// Sort companies in place, keeping those missing the sort field at the end
+fn sort_companies(companies: &mut [models::CannyCompany], sort: CompanySort) {
+    companies.sort_by(|a, b| match sort {
+        CompanySort::Spend => missing_last(a.monthly_spend, b.monthly_spend, |a, b| b.total_cmp(&a)),
+        CompanySort::Users => missing_last(a.user_count, b.user_count, |a, b| b.cmp(&a)),
+        CompanySort::Name => missing_last(a.name.as_deref(), b.name.as_deref(), |a, b| {
+            a.to_lowercase().cmp(&b.to_lowercase())
+        }),
+        CompanySort::Created => {
+            missing_last(a.created.as_deref(), b.created.as_deref(), |a, b| b.cmp(a))
+        }
+    });
+}
+
+fn print_companies_table(companies: &[models::CannyCompany]) {
+    output::print_table(
+        &["ID", "Name", "Users", "Monthly Spend", "Created"],
+        companies.iter().map(|company| {
+            vec![
+                company.id.clone(),
+                company.name.clone().unwrap_or_default(),
+                company.user_count.unwrap_or(0).to_string(),
+                company
+                    .monthly_spend
+                    .map(|m| format!("${:.2}", m))
+                    .unwrap_or_default(),
+                company.created.clone().unwrap_or_default(),
+            ]
+        }),
+    );
+}
+
+fn print_companies_csv(companies: &[models::CannyCompany]) {
+    output::print_csv(
+        &["id", "name", "userCount", "monthlySpend", "created"],
+        companies.iter().map(|company| {
+            vec![
+                company.id.clone(),
+                company.name.clone().unwrap_or_default(),
+                company.user_count.map(|c| c.to_string()).unwrap_or_default(),
+                company.monthly_spend.map(|m| m.to_string()).unwrap_or_default(),
+                company.created.clone().unwrap_or_default(),
+            ]
+        }),
+    );
+}
+
 fn print_company_detail(company: &models::CannyCompany) {
     let name = company.name.as_deref().unwrap_or("(no name)");
 
@@ -2727,12 +6692,17 @@ async fn handle_votes(client: &CannyClient, cmd: VotesCommands, json_output: boo
             limit,
             skip,
         } => {
-            let response = client
+            let (response, _) = client
                 .list_votes(post_id.as_deref(), user_id.as_deref(), Some(limit), Some(skip))
                 .await?;
 
             if json_output {
-                println!("{}", serde_json::to_string_pretty(&response.votes)?);
+                output::print_json_list(
+                    &response.votes,
+                    response.has_more,
+                    None,
+                    Some(skip + limit),
+                )?;
             } else {
                 if response.votes.is_empty() {
                     println!("No votes found.");
@@ -2756,22 +6726,28 @@ async fn handle_votes(client: &CannyClient, cmd: VotesCommands, json_output: boo
             let vote = client.get_vote(&id).await?;
             if let Some(vote) = vote {
                 if json_output {
-                    println!("{}", serde_json::to_string_pretty(&vote)?);
+                    output::print_json(&vote)?;
                 } else {
                     print_vote_detail(&vote);
                 }
             } else {
-                eprintln!("{}", "Vote not found.".red());
-                std::process::exit(1);
+                return Err(NotFound("Vote").into());
             }
         }
 
-        VotesCommands::Create { post_id, user_id } => {
-            client.create_vote(&post_id, &user_id).await?;
+        VotesCommands::Create {
+            post_id,
+            user_id,
+            by_id,
+            created_at,
+        } => {
+            client
+                .create_vote(&post_id, &user_id, by_id.as_deref(), created_at.as_deref())
+                .await?;
 
             if json_output {
                 println!(r#"{{"success": true}}"#);
-            } else {
+            } else if !output::is_quiet() {
                 println!("{} Vote created.", "✓".green());
             }
         }
@@ -2781,7 +6757,7 @@ async fn handle_votes(client: &CannyClient, cmd: VotesCommands, json_output: boo
 
             if json_output {
                 println!(r#"{{"success": true}}"#);
-            } else {
+            } else if !output::is_quiet() {
                 println!("{} Vote deleted.", "✓".green());
             }
         }
@@ -2839,23 +6815,63 @@ async fn handle_status_changes(
             board_id,
             limit,
             skip,
+            all,
+            count_only,
+            resolve_posts,
+            concurrency,
         } => {
-            let response = client
-                .list_status_changes(&board_id, Some(limit), Some(skip))
-                .await?;
+            let response = if all {
+                models::StatusChangesListResponse {
+                    has_more: false,
+                    status_changes: client.list_all_status_changes(&board_id).await?,
+                }
+            } else {
+                client
+                    .list_status_changes(&board_id, Some(limit), Some(skip))
+                    .await?
+                    .0
+            };
+            let titles = if resolve_posts {
+                status_change_post_titles(client, &response.status_changes, concurrency as usize)
+                    .await?
+            } else {
+                HashMap::new()
+            };
+            let title_for = |status_change: &models::CannyStatusChange| {
+                status_change
+                    .post_id
+                    .as_ref()
+                    .and_then(|id| titles.get(id))
+                    .map(String::as_str)
+            };
 
-            if json_output {
-                println!(
-                    "{}",
-                    serde_json::to_string_pretty(&response.status_changes)?
-                );
+            if count_only {
+                print_count(client, response.status_changes.len(), json_output)?;
+            } else if json_output && resolve_posts {
+                let items = response
+                    .status_changes
+                    .iter()
+                    .map(|status_change| {
+                        let mut record = serde_json::to_value(status_change)?;
+                        record["postTitle"] = serde_json::json!(title_for(status_change));
+                        Ok(record)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                output::print_json_list(&items, response.has_more, None, Some(skip + limit))?;
+            } else if json_output {
+                output::print_json_list(
+                    &response.status_changes,
+                    response.has_more,
+                    None,
+                    Some(skip + limit),
+                )?;
             } else {
                 if response.status_changes.is_empty() {
                     println!("No status changes found.");
                 } else {
                     println!("{}", "Status Changes:".bold());
                     for status_change in &response.status_changes {
-                        print_status_change(status_change);
+                        print_status_change(status_change, title_for(status_change));
                     }
                     if response.has_more {
                         println!(
@@ -2872,7 +6888,38 @@ async fn handle_status_changes(
     Ok(())
 }
 
-fn print_status_change(status_change: &models::CannyStatusChange) {
+/// Look up the title of every post the status changes refer to, fetching
+/// each post once. Posts that no longer exist are left out.
+async fn status_change_post_titles(
+    client: &CannyClient,
+    status_changes: &[models::CannyStatusChange],
+    concurrency: usize,
+) -> Result<HashMap<String, String>> {
+    let post_ids: HashSet<&str> = status_changes
+        .iter()
+        .filter_map(|status_change| status_change.post_id.as_deref())
+        .collect();
+
+    let posts: Vec<Option<models::CannyPost>> = futures::stream::iter(post_ids)
+        .map(|id| async move {
+            // A deleted post goes without a title instead of failing the list
+            match client.get_post(Some(id), None, None).await {
+                Err(e) if is_not_found(&e) => Ok(None),
+                result => result,
+            }
+        })
+        .buffer_unordered(concurrency)
+        .try_collect()
+        .await?;
+
+    Ok(posts
+        .into_iter()
+        .flatten()
+        .map(|post| (post.id, post.title))
+        .collect())
+}
+
+fn print_status_change(status_change: &models::CannyStatusChange, post_title: Option<&str>) {
     let changer_name = status_change
         .changer
         .as_ref()
@@ -2901,7 +6948,10 @@ fn print_status_change(status_change: &models::CannyStatusChange) {
     );
 
     if let Some(ref post_id) = status_change.post_id {
-        println!("    Post ID: {}", post_id.dimmed());
+        match post_title {
+            Some(title) => println!("    Post: {} {}", title.bold(), post_id.dimmed()),
+            None => println!("    Post ID: {}", post_id.dimmed()),
+        }
     }
 }
 
@@ -2935,7 +6985,12 @@ async fn handle_changelog(
                 .await?;
 
             if json_output {
-                println!("{}", serde_json::to_string_pretty(&response.entries)?);
+                output::print_json_list(
+                    &response.entries,
+                    response.has_more,
+                    None,
+                    Some(skip + limit),
+                )?;
             } else {
                 if response.entries.is_empty() {
                     println!("No changelog entries found.");
@@ -2965,34 +7020,46 @@ async fn handle_changelog(
             label_ids,
             published_on,
             scheduled_for,
+            input_json,
         } => {
-            let post_ids_refs: Option<Vec<&str>> = if post_ids.is_empty() {
-                None
-            } else {
-                Some(post_ids.iter().map(|s| s.as_str()).collect())
-            };
-            let label_ids_refs: Option<Vec<&str>> = if label_ids.is_empty() {
-                None
+            let id = if let Some(input) = input_json {
+                let body = read_input_json(&input, &["title"])?;
+                client
+                    .create_from_body("entries/create", body)
+                    .await?
             } else {
-                Some(label_ids.iter().map(|s| s.as_str()).collect())
-            };
+                let title = title.context("Either --title or --input-json must be provided")?;
+                let details = details.as_deref().map(resolve_text_arg).transpose()?;
+                let post_ids_refs: Option<Vec<&str>> = if post_ids.is_empty() {
+                    None
+                } else {
+                    Some(post_ids.iter().map(|s| s.as_str()).collect())
+                };
+                let label_ids_refs: Option<Vec<&str>> = if label_ids.is_empty() {
+                    None
+                } else {
+                    Some(label_ids.iter().map(|s| s.as_str()).collect())
+                };
 
-            let id = client
-                .create_entry(
-                    &title,
-                    details.as_deref(),
-                    entry_type.as_deref(),
-                    published,
-                    notify,
-                    post_ids_refs,
-                    label_ids_refs,
-                    published_on.as_deref(),
-                    scheduled_for.as_deref(),
-                )
-                .await?;
+                client
+                    .create_entry(
+                        &title,
+                        details.as_deref(),
+                        entry_type.as_deref(),
+                        published.then_some(true),
+                        notify.then_some(true),
+                        post_ids_refs,
+                        label_ids_refs,
+                        published_on.as_deref(),
+                        scheduled_for.as_deref(),
+                    )
+                    .await?
+            };
 
             if json_output {
                 println!(r#"{{"id": "{}"}}"#, id);
+            } else if output::is_quiet() {
+                println!("{}", id);
             } else {
                 println!(
                     "{} Created changelog entry with ID: {}",
@@ -3006,22 +7073,25 @@ async fn handle_changelog(
             let entry = client.get_entry(&id).await?;
             if let Some(entry) = entry {
                 if json_output {
-                    println!("{}", serde_json::to_string_pretty(&entry)?);
+                    output::print_json(&entry)?;
                 } else {
                     print_entry_detail(&entry);
                 }
             } else {
-                eprintln!("{}", "Changelog entry not found.".red());
-                std::process::exit(1);
+                return Err(NotFound("Changelog entry").into());
             }
         }
 
+        ChangelogCommands::Labels(cmd) => {
+            handle_changelog_labels(client, cmd, json_output).await?;
+        }
+
         ChangelogCommands::Delete { id } => {
             client.delete_entry(&id).await?;
 
             if json_output {
                 println!(r#"{{"success": true}}"#);
-            } else {
+            } else if !output::is_quiet() {
                 println!("{} Changelog entry deleted.", "✓".green());
             }
         }
@@ -3048,14 +7118,14 @@ async fn handle_changelog(
                     details.as_deref(),
                     entry_type.as_deref(),
                     published,
-                    notify,
+                    notify.then_some(true),
                     label_ids_refs,
                 )
                 .await?;
 
             if json_output {
                 println!(r#"{{"success": true}}"#);
-            } else {
+            } else if !output::is_quiet() {
                 println!("{} Changelog entry updated.", "✓".green());
             }
         }
@@ -3064,6 +7134,47 @@ async fn handle_changelog(
     Ok(())
 }
 
+async fn handle_changelog_labels(
+    client: &CannyClient,
+    cmd: ChangelogLabelsCommands,
+    json_output: bool,
+) -> Result<()> {
+    match cmd {
+        ChangelogLabelsCommands::List => {
+            let labels = client.list_entry_labels().await?;
+
+            if json_output {
+                output::print_json(&labels)?;
+            } else if labels.is_empty() {
+                println!("No changelog labels found.");
+            } else {
+                println!("{}", "Changelog labels:".bold());
+                for label in &labels {
+                    println!(
+                        "  {} {}",
+                        label.name.as_deref().unwrap_or("(no name)").magenta(),
+                        format!("({})", label.id).dimmed()
+                    );
+                }
+            }
+        }
+
+        ChangelogLabelsCommands::Create { name } => {
+            let id = client.create_entry_label(&name).await?;
+
+            if json_output {
+                println!(r#"{{"id": "{}"}}"#, id);
+            } else if output::is_quiet() {
+                println!("{}", id);
+            } else {
+                println!("{} Created label with ID: {}", "✓".green(), id.cyan());
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn print_entry(entry: &models::CannyEntry) {
     let title = entry.title.as_deref().unwrap_or("(no title)");
     let status = entry.status.as_deref().unwrap_or("draft").to_uppercase();
@@ -3122,10 +7233,34 @@ fn print_entry_detail(entry: &models::CannyEntry) {
         println!("Published: {}", published_at.dimmed());
     }
 
+    if let Some(ref scheduled_for) = entry.scheduled_for {
+        println!("Scheduled: {}", scheduled_for.cyan());
+    }
+
     if let Some(ref created) = entry.created {
         println!("Created: {}", created.dimmed());
     }
 
+    // Labels come back as objects with a name, but tolerate bare strings
+    let labels: Vec<String> = entry
+        .labels
+        .as_ref()
+        .and_then(|l| l.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|label| {
+            label
+                .get("name")
+                .or_else(|| label.get("id"))
+                .unwrap_or(label)
+                .as_str()
+                .map(str::to_string)
+        })
+        .collect();
+    if !labels.is_empty() {
+        println!("Labels: {}", labels.join(", ").magenta());
+    }
+
     if let Some(ref url) = entry.url {
         println!("URL: {}", url.underline());
     }
@@ -3133,7 +7268,7 @@ fn print_entry_detail(entry: &models::CannyEntry) {
     if let Some(ref details) = entry.details {
         if !details.is_empty() {
             println!("\n{}", "Details:".bold());
-            println!("{}", details);
+            println!("{}", output::markdown(details));
         }
     }
 }
@@ -3148,13 +7283,25 @@ async fn handle_opportunities(
             post_id,
             limit,
             skip,
+            sort,
         } => {
-            let response = client
+            let mut response = client
                 .list_opportunities(&post_id, Some(limit), Some(skip))
                 .await?;
 
+            if let Some(OpportunitySort::Value) = sort {
+                response.opportunities.sort_by(|a, b| {
+                    missing_last(a.value, b.value, |a, b| b.total_cmp(&a))
+                });
+            }
+
             if json_output {
-                println!("{}", serde_json::to_string_pretty(&response.opportunities)?);
+                output::print_json_list(
+                    &response.opportunities,
+                    response.has_more,
+                    None,
+                    Some(skip + limit),
+                )?;
             } else {
                 if response.opportunities.is_empty() {
                     println!("No opportunities found.");
@@ -3163,6 +7310,7 @@ async fn handle_opportunities(
                     for opportunity in &response.opportunities {
                         print_opportunity(opportunity);
                     }
+                    print_opportunity_summary(&response.opportunities);
                     if response.has_more {
                         println!(
                             "\n{} Use --skip {} to see more.",
@@ -3178,6 +7326,23 @@ async fn handle_opportunities(
     Ok(())
 }
 
+/// Print the total value and open/won/lost counts of the listed opportunities
+fn print_opportunity_summary(opportunities: &[models::CannyOpportunity]) {
+    let total: f64 = opportunities.iter().filter_map(|o| o.value).sum();
+    let (mut open, mut won, mut lost) = (0, 0, 0);
+    for opportunity in opportunities {
+        match (opportunity.closed.unwrap_or(false), opportunity.won.unwrap_or(false)) {
+            (false, _) => open += 1,
+            (true, true) => won += 1,
+            (true, false) => lost += 1,
+        }
+    }
+
+    println!();
+    println!("Total value: {}", format!("${:.2}", total).green());
+    println!("Open/Won/Lost: {}/{}/{}", open, won, lost);
+}
+
 fn print_opportunity(opportunity: &models::CannyOpportunity) {
     let name = opportunity.name.as_deref().unwrap_or("(no name)");
 
@@ -3207,34 +7372,82 @@ fn print_opportunity(opportunity: &models::CannyOpportunity) {
     }
 }
 
-async fn handle_groups(client: &CannyClient, cmd: GroupsCommands, json_output: bool) -> Result<()> {
+async fn handle_groups(
+    client: &CannyClient,
+    cmd: GroupsCommands,
+    format: OutputFormat,
+) -> Result<()> {
+    let json_output = format.is_json();
+
     match cmd {
-        GroupsCommands::List { limit, cursor } => {
-            let response = client
-                .list_groups(Some(limit), cursor.as_deref())
-                .await?;
+        GroupsCommands::List {
+            limit,
+            cursor,
+            all,
+            count_only,
+            cursor_file,
+        } => {
+            list_with_cursor_file(cursor_file.as_deref(), cursor, async |cursor| {
+                if all && format == OutputFormat::Ndjson && !count_only {
+                    let resume_cursor = client
+                        .for_each_group_page(cursor, |groups| {
+                            groups.iter().try_for_each(output::print_json_line)
+                        })
+                        .await?;
+                    return Ok(resume_cursor);
+                }
 
-            if json_output {
-                println!("{}", serde_json::to_string_pretty(&response.groups)?);
-            } else {
-                if response.groups.is_empty() {
-                    println!("No groups found.");
+                let (response, resume_cursor) = if all {
+                    let mut groups = Vec::new();
+                    let resume_cursor = client
+                        .for_each_group_page(cursor, |page| {
+                            groups.extend(page);
+                            Ok(())
+                        })
+                        .await?;
+                    let response = models::GroupsListResponse {
+                        has_more: false,
+                        cursor: None,
+                        groups,
+                    };
+                    (response, resume_cursor)
                 } else {
-                    println!("{}", "Groups:".bold());
-                    for group in &response.groups {
-                        print_group(group);
-                    }
-                    if response.has_more {
-                        if let Some(ref next_cursor) = response.cursor {
-                            println!(
-                                "\n{} Use --cursor {} to see more.",
-                                "More groups available.".dimmed(),
-                                next_cursor
-                            );
+                    let response = client.list_groups(Some(limit), cursor.as_deref()).await?;
+                    let resume_cursor = response.cursor.clone().or(cursor);
+                    (response, resume_cursor)
+                };
+
+                if count_only {
+                    print_count(client, response.groups.len(), json_output)?;
+                } else if json_output {
+                    output::print_json_list(
+                        &response.groups,
+                        response.has_more,
+                        response.cursor.as_deref(),
+                        None,
+                    )?;
+                } else {
+                    if response.groups.is_empty() {
+                        println!("No groups found.");
+                    } else {
+                        println!("{}", "Groups:".bold());
+                        for group in &response.groups {
+                            print_group(group);
+                        }
+                        if response.has_more {
+                            if let Some(ref next_cursor) = response.cursor {
+                                println!(
+                                    "\n{} Use --cursor {} to see more.",
+                                    "More groups available.".dimmed(),
+                                    next_cursor
+                                );
+                            }
                         }
                     }
                 }
-            }
+                Ok(resume_cursor)
+            })
+            .await?;
         }
 
         GroupsCommands::Get { id, url_name } => {
@@ -3245,13 +7458,12 @@ async fn handle_groups(client: &CannyClient, cmd: GroupsCommands, json_output: b
             let group = client.get_group(id.as_deref(), url_name.as_deref()).await?;
             if let Some(group) = group {
                 if json_output {
-                    println!("{}", serde_json::to_string_pretty(&group)?);
+                    output::print_json(&group)?;
                 } else {
                     print_group_detail(&group);
                 }
             } else {
-                eprintln!("{}", "Group not found.".red());
-                std::process::exit(1);
+                return Err(NotFound("Group").into());
             }
         }
     }
@@ -3297,52 +7509,94 @@ fn print_group_detail(group: &models::CannyGroup) {
 async fn handle_insights(
     client: &CannyClient,
     cmd: InsightsCommands,
-    json_output: bool,
+    format: OutputFormat,
 ) -> Result<()> {
+    let json_output = format.is_json();
+
     match cmd {
         InsightsCommands::List {
             limit,
             cursor,
             idea_id,
+            all,
+            count_only,
+            cursor_file,
         } => {
-            let response = client
-                .list_insights(Some(limit), cursor.as_deref(), idea_id.as_deref())
-                .await?;
+            list_with_cursor_file(cursor_file.as_deref(), cursor, async |cursor| {
+                if all && format == OutputFormat::Ndjson && !count_only {
+                    let resume_cursor = client
+                        .for_each_insight_page(idea_id.as_deref(), cursor, |insights| {
+                            insights.iter().try_for_each(output::print_json_line)
+                        })
+                        .await?;
+                    return Ok(resume_cursor);
+                }
 
-            if json_output {
-                println!("{}", serde_json::to_string_pretty(&response.insights)?);
-            } else {
-                if response.insights.is_empty() {
-                    println!("No insights found.");
+                let (response, resume_cursor) = if all {
+                    let mut insights = Vec::new();
+                    let resume_cursor = client
+                        .for_each_insight_page(idea_id.as_deref(), cursor, |page| {
+                            insights.extend(page);
+                            Ok(())
+                        })
+                        .await?;
+                    let response = models::InsightsListResponse {
+                        has_more: false,
+                        cursor: None,
+                        insights,
+                    };
+                    (response, resume_cursor)
                 } else {
-                    println!("{}", "Insights:".bold());
-                    for insight in &response.insights {
-                        print_insight(insight);
-                    }
-                    if response.has_more {
-                        if let Some(ref next_cursor) = response.cursor {
-                            println!(
-                                "\n{} Use --cursor {} to see more.",
-                                "More insights available.".dimmed(),
-                                next_cursor
-                            );
+                    let response = client
+                        .list_insights(Some(limit), cursor.as_deref(), idea_id.as_deref())
+                        .await?;
+                    let resume_cursor = response.cursor.clone().or(cursor);
+                    (response, resume_cursor)
+                };
+
+                if count_only {
+                    print_count(client, response.insights.len(), json_output)?;
+                } else if json_output {
+                    output::print_json_list(
+                        &response.insights,
+                        response.has_more,
+                        response.cursor.as_deref(),
+                        None,
+                    )?;
+                } else {
+                    if response.insights.is_empty() {
+                        println!("No insights found.");
+                    } else {
+                        println!("{}", "Insights:".bold());
+                        for insight in &response.insights {
+                            print_insight(insight);
+                        }
+                        if response.has_more {
+                            if let Some(ref next_cursor) = response.cursor {
+                                println!(
+                                    "\n{} Use --cursor {} to see more.",
+                                    "More insights available.".dimmed(),
+                                    next_cursor
+                                );
+                            }
                         }
                     }
                 }
-            }
+                Ok(resume_cursor)
+            })
+            .await?;
         }
 
         InsightsCommands::Get { id } => {
             let insight = client.get_insight(&id).await?;
             if let Some(insight) = insight {
                 if json_output {
-                    println!("{}", serde_json::to_string_pretty(&insight)?);
+                    output::print_json(&insight)?;
                 } else {
                     print_insight_detail(&insight);
                 }
             } else {
-                eprintln!("{}", "Insight not found.".red());
-                std::process::exit(1);
+                return Err(NotFound("Insight").into());
             }
         }
     }
@@ -3350,10 +7604,27 @@ async fn handle_insights(
     Ok(())
 }
 
+/// A description collapsed onto one line and cut to
+/// `DESCRIPTION_PREVIEW_CHARS`, or `None` if it's blank
+fn description_preview(description: &str) -> Option<String> {
+    let line = description.split_whitespace().collect::<Vec<_>>().join(" ");
+    if line.is_empty() {
+        return None;
+    }
+    if line.chars().count() <= DESCRIPTION_PREVIEW_CHARS {
+        return Some(line);
+    }
+    let cut: String = line.chars().take(DESCRIPTION_PREVIEW_CHARS - 1).collect();
+    Some(format!("{}…", cut.trim_end()))
+}
+
 fn print_insight(insight: &models::CannyInsight) {
     let title = insight.title.as_deref().unwrap_or("(no title)");
 
     println!("\n  {} {}", insight.id.dimmed(), title.cyan());
+    if let Some(preview) = insight.description.as_deref().and_then(description_preview) {
+        println!("    {}", preview);
+    }
     if let Some(ref url) = insight.url {
         println!("    URL: {}", url.underline());
     }
@@ -3386,44 +7657,86 @@ fn print_insight_detail(insight: &models::CannyInsight) {
     }
 }
 
-async fn handle_ideas(client: &CannyClient, cmd: IdeasCommands, json_output: bool) -> Result<()> {
+async fn handle_ideas(
+    client: &CannyClient,
+    cmd: IdeasCommands,
+    format: OutputFormat,
+) -> Result<()> {
+    let json_output = format.is_json();
+
     match cmd {
         IdeasCommands::List {
             limit,
             cursor,
             parent_id,
             search,
+            all,
+            count_only,
+            cursor_file,
         } => {
-            let response = client
-                .list_ideas(
-                    Some(limit),
-                    cursor.as_deref(),
-                    parent_id.as_deref(),
-                    search.as_deref(),
-                )
-                .await?;
+            list_with_cursor_file(cursor_file.as_deref(), cursor, async |cursor| {
+                let (parent_id, search) = (parent_id.as_deref(), search.as_deref());
+                if all && format == OutputFormat::Ndjson && !count_only {
+                    let resume_cursor = client
+                        .for_each_idea_page(parent_id, search, cursor, |ideas| {
+                            ideas.iter().try_for_each(output::print_json_line)
+                        })
+                        .await?;
+                    return Ok(resume_cursor);
+                }
 
-            if json_output {
-                println!("{}", serde_json::to_string_pretty(&response.ideas)?);
-            } else {
-                if response.ideas.is_empty() {
-                    println!("No ideas found.");
+                let (response, resume_cursor) = if all {
+                    let mut ideas = Vec::new();
+                    let resume_cursor = client
+                        .for_each_idea_page(parent_id, search, cursor, |page| {
+                            ideas.extend(page);
+                            Ok(())
+                        })
+                        .await?;
+                    let response = models::IdeasListResponse {
+                        has_more: false,
+                        cursor: None,
+                        ideas,
+                    };
+                    (response, resume_cursor)
                 } else {
-                    println!("{}", "Ideas:".bold());
-                    for idea in &response.ideas {
-                        print_idea(idea);
-                    }
-                    if response.has_more {
-                        if let Some(ref next_cursor) = response.cursor {
-                            println!(
-                                "\n{} Use --cursor {} to see more.",
-                                "More ideas available.".dimmed(),
-                                next_cursor
-                            );
+                    let response =
+                        client.list_ideas(Some(limit), cursor.as_deref(), parent_id, search).await?;
+                    let resume_cursor = response.cursor.clone().or(cursor);
+                    (response, resume_cursor)
+                };
+
+                if count_only {
+                    print_count(client, response.ideas.len(), json_output)?;
+                } else if json_output {
+                    output::print_json_list(
+                        &response.ideas,
+                        response.has_more,
+                        response.cursor.as_deref(),
+                        None,
+                    )?;
+                } else {
+                    if response.ideas.is_empty() {
+                        println!("No ideas found.");
+                    } else {
+                        println!("{}", "Ideas:".bold());
+                        for idea in &response.ideas {
+                            print_idea(idea);
+                        }
+                        if response.has_more {
+                            if let Some(ref next_cursor) = response.cursor {
+                                println!(
+                                    "\n{} Use --cursor {} to see more.",
+                                    "More ideas available.".dimmed(),
+                                    next_cursor
+                                );
+                            }
                         }
                     }
                 }
-            }
+                Ok(resume_cursor)
+            })
+            .await?;
         }
 
         IdeasCommands::Get { id, url_name } => {
@@ -3434,13 +7747,12 @@ async fn handle_ideas(client: &CannyClient, cmd: IdeasCommands, json_output: boo
             let idea = client.get_idea(id.as_deref(), url_name.as_deref()).await?;
             if let Some(idea) = idea {
                 if json_output {
-                    println!("{}", serde_json::to_string_pretty(&idea)?);
+                    output::print_json(&idea)?;
                 } else {
                     print_idea_detail(&idea);
                 }
             } else {
-                eprintln!("{}", "Idea not found.".red());
-                std::process::exit(1);
+                return Err(NotFound("Idea").into());
             }
         }
     }
@@ -3452,6 +7764,9 @@ fn print_idea(idea: &models::CannyIdea) {
     let name = idea.name.as_deref().unwrap_or("(no name)");
 
     println!("\n  {} {}", idea.id.dimmed(), name.cyan());
+    if let Some(preview) = idea.description.as_deref().and_then(description_preview) {
+        println!("    {}", preview);
+    }
     if let Some(post_count) = idea.post_count {
         println!("    Posts: {}", post_count);
     }
@@ -3508,6 +7823,8 @@ async fn handle_autopilot(
 
             if json_output {
                 println!(r#"{{"id": "{}"}}"#, id);
+            } else if output::is_quiet() {
+                println!("{}", id);
             } else {
                 println!(
                     "{} Enqueued feedback with ID: {}",
@@ -45,6 +45,9 @@ pub struct CannyUser {
     pub email: Option<String>,
     #[serde(default)]
     pub avatar_url: Option<String>,
+    /// Only included on some objects, such as voters
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub companies: Vec<CannyCompany>,
 }
 
 /// Represents a Canny category
@@ -80,6 +83,23 @@ pub struct CannyPost {
     pub author: Option<CannyUser>,
     #[serde(default)]
     pub category: Option<CannyCategory>,
+    #[serde(default)]
+    pub tags: Vec<CannyTag>,
+    #[serde(default)]
+    pub board: Option<CannyBoard>,
+    #[serde(default)]
+    pub owner: Option<CannyUser>,
+    #[serde(default)]
+    pub eta: Option<String>,
+    #[serde(default)]
+    pub eta_public: Option<bool>,
+    /// Admin who created the post on behalf of the author
+    #[serde(default)]
+    pub by: Option<CannyUser>,
+    #[serde(default)]
+    pub jira: Option<serde_json::Value>,
+    #[serde(default)]
+    pub merge_history: Option<serde_json::Value>,
 }
 
 /// Represents a Canny comment
@@ -97,6 +117,8 @@ pub struct CannyComment {
     pub parent_id: Option<String>,
     #[serde(default)]
     pub pinned: Option<bool>,
+    #[serde(default)]
+    pub internal: Option<bool>,
 }
 
 /// Response from posts/list endpoint
@@ -183,6 +205,10 @@ pub struct CannyUserFull {
     pub user_id: Option<String>,
     #[serde(default)]
     pub url: Option<String>,
+    #[serde(default)]
+    pub custom_fields: Option<serde_json::Value>,
+    #[serde(default)]
+    pub companies: Vec<CannyCompany>,
 }
 
 /// Response from users/find endpoint
@@ -228,6 +254,98 @@ impl std::fmt::Display for PostSort {
     }
 }
 
+/// How `posts list` groups posts under headers in text output
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum PostGroup {
+    /// By category name; posts without one are "Uncategorized"
+    Category,
+    /// By status
+    Status,
+    /// By author name
+    Author,
+}
+
+/// Client-side sort orders for companies; missing values always sort last
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum CompanySort {
+    /// Highest monthly spend first
+    Spend,
+    /// Most users first
+    Users,
+    /// Alphabetical by name
+    Name,
+    /// Newest first
+    Created,
+}
+
+/// Client-side sort orders for opportunities; missing values always sort last
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum OpportunitySort {
+    /// Highest value first
+    Value,
+}
+
+/// What a create command prints once the object exists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum CreateReturn {
+    /// Only the new ID
+    Id,
+    /// The whole object, fetched again after creating it
+    Full,
+}
+
+/// A post read from an import file (one NDJSON object or CSV row)
+#[derive(Debug, Default, Deserialize)]
+pub struct PostImportRecord {
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub details: Option<String>,
+    #[serde(default, rename = "authorID")]
+    pub author_id: Option<String>,
+    #[serde(default, rename = "categoryID")]
+    pub category_id: Option<String>,
+    #[serde(default, rename = "createdAt")]
+    pub created_at: Option<String>,
+    #[serde(default, rename = "customFields")]
+    pub custom_fields: Option<serde_json::Value>,
+}
+
+/// A user read from a `users create --from-file` NDJSON line
+#[derive(Debug, Default, Deserialize)]
+pub struct UserImportRecord {
+    #[serde(default, rename = "userID")]
+    pub user_id: Option<String>,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default, rename = "avatarURL")]
+    pub avatar_url: Option<String>,
+    #[serde(default, rename = "companyID")]
+    pub company_id: Option<String>,
+    #[serde(default, rename = "customFields")]
+    pub custom_fields: Option<serde_json::Value>,
+}
+
+/// Sort options for posts merged from several boards (highest first)
+#[derive(Debug, Clone, Copy, clap::ValueEnum, Default)]
+pub enum MergedPostSort {
+    #[default]
+    Score,
+    Created,
+}
+
+impl MergedPostSort {
+    /// The equivalent server-side sort, used to pick each board's top posts
+    pub fn api_sort(self) -> &'static str {
+        match self {
+            MergedPostSort::Score => "score",
+            MergedPostSort::Created => "newest",
+        }
+    }
+}
+
 /// Represents a Canny tag
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -327,6 +445,10 @@ pub struct CannyEntry {
     pub entry_type: Option<String>,
     #[serde(default)]
     pub url: Option<String>,
+    #[serde(default)]
+    pub scheduled_for: Option<String>,
+    #[serde(default)]
+    pub labels: Option<serde_json::Value>,
 }
 
 /// Response from entries/list endpoint
@@ -337,6 +459,21 @@ pub struct EntriesListResponse {
     pub entries: Vec<CannyEntry>,
 }
 
+/// Represents a label that can be attached to changelog entries
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CannyEntryLabel {
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// Response from entries/labels/list endpoint
+#[derive(Debug, Deserialize)]
+pub struct EntryLabelsListResponse {
+    #[serde(default)]
+    pub labels: Vec<CannyEntryLabel>,
+}
+
 /// Response from entries/retrieve endpoint
 #[derive(Debug, Deserialize)]
 pub struct EntryRetrieveResponse {
@@ -370,6 +507,18 @@ pub struct OpportunitiesListResponse {
     pub opportunities: Vec<CannyOpportunity>,
 }
 
+/// Represents a Canny user segment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CannySegment {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub url_name: Option<String>,
+    #[serde(default)]
+    pub created: Option<String>,
+}
+
 /// Represents a Canny group
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -0,0 +1,485 @@
+use std::fmt;
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+
+use clap::ValueEnum;
+use comfy_table::presets::{ASCII_FULL, UTF8_FULL};
+use colored::Colorize;
+use comfy_table::{ContentArrangement, Row, Table};
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
+use serde::Serialize;
+use serde_json::Value;
+use termimad::MadSkin;
+
+/// Output format for command results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable colored text
+    Text,
+    /// Pretty-printed JSON
+    Json,
+    /// Comma-separated values with a header row (list commands only)
+    Csv,
+    /// Aligned columns sized to the terminal (list commands only)
+    Table,
+    /// One compact JSON object per line; `--all` lists print each page as
+    /// soon as it's fetched
+    Ndjson,
+}
+
+impl OutputFormat {
+    /// Whether results are written as JSON (pretty or one item per line)
+    pub fn is_json(self) -> bool {
+        matches!(self, OutputFormat::Json | OutputFormat::Ndjson)
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutputFormat::Text => write!(f, "text"),
+            OutputFormat::Json => write!(f, "json"),
+            OutputFormat::Csv => write!(f, "csv"),
+            OutputFormat::Table => write!(f, "table"),
+            OutputFormat::Ndjson => write!(f, "ndjson"),
+        }
+    }
+}
+
+/// When to emit ANSI colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ColorChoice {
+    /// Color when stdout is a terminal and NO_COLOR is not set
+    Auto,
+    /// Always color, even when piped
+    Always,
+    /// Never color
+    Never,
+}
+
+/// Apply the color choice globally for every `colored` string
+pub fn configure_color(choice: ColorChoice) {
+    let enabled = match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => {
+            std::env::var_os("NO_COLOR").is_none() && std::io::stdout().is_terminal()
+        }
+    };
+    colored::control::set_override(enabled);
+}
+
+/// When to render markdown in post and changelog details
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum MarkdownChoice {
+    /// Render when colors are enabled
+    Auto,
+    /// Always render (unstyled when colors are disabled)
+    Always,
+    /// Print the raw markdown source
+    Never,
+}
+
+/// Whether details are rendered as markdown
+static MARKDOWN: AtomicBool = AtomicBool::new(false);
+
+/// Apply the markdown choice; call after `configure_color`
+pub fn configure_markdown(choice: MarkdownChoice) {
+    let enabled = match choice {
+        MarkdownChoice::Always => true,
+        MarkdownChoice::Never => false,
+        MarkdownChoice::Auto => colored::control::SHOULD_COLORIZE.should_colorize(),
+    };
+    MARKDOWN.store(enabled, Ordering::Relaxed);
+}
+
+/// Format markdown as styled text wrapped to the terminal width, or return it
+/// unchanged when rendering is off
+pub fn markdown(text: &str) -> String {
+    if !MARKDOWN.load(Ordering::Relaxed) {
+        return text.to_string();
+    }
+    let skin = if colored::control::SHOULD_COLORIZE.should_colorize() {
+        MadSkin::default()
+    } else {
+        MadSkin::no_style()
+    };
+    skin.term_text(text).to_string().trim_end().to_string()
+}
+
+/// Whether --quiet was given
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Suppress decorative output: creates print only the new ID, and other
+/// mutations print nothing
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+pub fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// Progress feedback for a long-running command, drawn on stderr and cleared
+/// when dropped. Hidden with --quiet and unless both stdout and stderr are
+/// terminals, so piped output and logs never contain it.
+pub struct Progress(ProgressBar);
+
+impl Progress {
+    /// A spinner with a running count, for when the total isn't known
+    pub fn spinner(message: &'static str) -> Self {
+        let bar = Self::new_bar(None);
+        bar.set_style(ProgressStyle::with_template("{spinner} {msg} {pos}").unwrap());
+        bar.set_message(message);
+        bar.enable_steady_tick(std::time::Duration::from_millis(100));
+        Progress(bar)
+    }
+
+    /// Progress that's never shown, for when the output would clash with it
+    pub fn hidden() -> Self {
+        Progress(ProgressBar::hidden())
+    }
+
+    /// A bar counting up to `total`
+    pub fn bar(message: &'static str, total: usize) -> Self {
+        let bar = Self::new_bar(Some(total as u64));
+        bar.set_style(
+            ProgressStyle::with_template("{msg} [{bar:30}] {pos}/{len}")
+                .unwrap()
+                .progress_chars("=> "),
+        );
+        bar.set_message(message);
+        Progress(bar)
+    }
+
+    fn new_bar(total: Option<u64>) -> ProgressBar {
+        let visible =
+            !is_quiet() && std::io::stdout().is_terminal() && std::io::stderr().is_terminal();
+        if !visible {
+            return ProgressBar::hidden();
+        }
+        // Always stderr, so stdout holds nothing but the CSV or JSON itself
+        ProgressBar::with_draw_target(total, ProgressDrawTarget::stderr())
+    }
+
+    /// Set how many items are done
+    pub fn set(&self, done: usize) {
+        self.0.set_position(done as u64);
+    }
+
+    /// Count one more item as done
+    pub fn inc(&self) {
+        self.0.inc(1);
+    }
+
+    /// Print a line to stdout above the progress bar
+    pub fn println(&self, line: &str) {
+        self.0.suspend(|| println!("{}", line));
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        self.0.finish_and_clear();
+    }
+}
+
+/// Whether --compact was given
+static COMPACT: AtomicBool = AtomicBool::new(false);
+
+/// Emit JSON on a single line instead of pretty-printed
+pub fn set_compact(compact: bool) {
+    COMPACT.store(compact, Ordering::Relaxed);
+}
+
+/// Whether --format ndjson was given
+static JSON_LINES: AtomicBool = AtomicBool::new(false);
+
+/// Print list items one JSON object per line instead of in an envelope, and
+/// everything else on a single line
+pub fn set_json_lines(json_lines: bool) {
+    JSON_LINES.store(json_lines, Ordering::Relaxed);
+}
+
+/// Serialize JSON output, pretty-printed unless --compact or --format ndjson
+/// was given
+pub fn json_string<T: Serialize + ?Sized>(value: &T) -> serde_json::Result<String> {
+    if COMPACT.load(Ordering::Relaxed) || JSON_LINES.load(Ordering::Relaxed) {
+        serde_json::to_string(value)
+    } else {
+        serde_json::to_string_pretty(value)
+    }
+}
+
+/// Top-level keys to keep in JSON output, from --fields
+static JSON_FIELDS: OnceLock<Vec<String>> = OnceLock::new();
+
+/// Restrict JSON output to the given top-level keys (empty keeps everything)
+pub fn set_json_fields(fields: Vec<String>) {
+    let _ = JSON_FIELDS.set(fields);
+}
+
+/// Whether unknown --fields have already been warned about, so streamed
+/// lines don't repeat the warning
+static FIELDS_WARNED: AtomicBool = AtomicBool::new(false);
+
+/// Project an object, or each object in an array, down to the --fields keys.
+/// Requested keys that none of the objects have are reported as a warning.
+fn project_fields(value: Value) -> Value {
+    let Some(fields) = JSON_FIELDS.get().filter(|f| !f.is_empty()) else {
+        return value;
+    };
+
+    let mut seen = vec![false; fields.len()];
+    let mut project = |value: Value| match value {
+        Value::Object(mut map) => {
+            let mut projected = serde_json::Map::new();
+            for (i, field) in fields.iter().enumerate() {
+                if let Some(v) = map.remove(field) {
+                    seen[i] = true;
+                    projected.insert(field.clone(), v);
+                }
+            }
+            Value::Object(projected)
+        }
+        other => other,
+    };
+
+    let (projected, any_objects) = match value {
+        Value::Array(items) => {
+            let any_objects = items.iter().any(Value::is_object);
+            (Value::Array(items.into_iter().map(&mut project).collect()), any_objects)
+        }
+        value @ Value::Object(_) => (project(value), true),
+        other => (other, false),
+    };
+
+    if any_objects && !FIELDS_WARNED.swap(true, Ordering::Relaxed) {
+        for (field, _) in fields.iter().zip(seen).filter(|(_, seen)| !seen) {
+            eprintln!("{} unknown field '{}' in --fields", "warning:".yellow(), field);
+        }
+    }
+
+    projected
+}
+
+/// Per-item format string for list output, from --template
+static TEMPLATE: OnceLock<String> = OnceLock::new();
+
+/// Print items through `template` instead of as JSON. Fails if a `{` has
+/// no closing `}`, which would otherwise swallow the rest of the template.
+pub fn set_template(template: String) -> anyhow::Result<()> {
+    check_template(&template)?;
+    let _ = TEMPLATE.set(template);
+    Ok(())
+}
+
+/// Check that every `{field}` placeholder in `template` is closed
+fn check_template(template: &str) -> anyhow::Result<()> {
+    let mut chars = template.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '{' if chars.peek().map(|&(_, c)| c) == Some('{') => {
+                chars.next();
+            }
+            '{' if !chars.by_ref().any(|(_, c)| c == '}') => {
+                anyhow::bail!(
+                    "unterminated placeholder at '{}' in --template; write {{{{ for a literal {{",
+                    &template[i..]
+                );
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Render `template` for one item: `{field}` or `{nested.field}` is replaced
+/// with the value at that path (empty when missing or null), `{{`/`}}` are
+/// literal braces, and `\t`, `\n` and `\\` are unescaped.
+fn render_template(template: &str, item: &Value) -> String {
+    let mut out = String::new();
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('t') => out.push('\t'),
+                Some('n') => out.push('\n'),
+                Some('\\') => out.push('\\'),
+                Some(other) => {
+                    out.push('\\');
+                    out.push(other);
+                }
+                None => out.push('\\'),
+            },
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                out.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                out.push('}');
+            }
+            '{' => {
+                let path: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                let value = path
+                    .split('.')
+                    .try_fold(item, |value, key| value.get(key.trim()));
+                match value {
+                    None | Some(Value::Null) => {}
+                    Some(Value::String(s)) => out.push_str(s),
+                    Some(other) => out.push_str(&other.to_string()),
+                }
+            }
+            c => out.push(c),
+        }
+    }
+
+    out
+}
+
+/// Print a value as pretty JSON, applying --fields. With --template, a
+/// single item is printed as one rendered line instead.
+pub fn print_json<T: Serialize>(value: &T) -> anyhow::Result<()> {
+    if let Some(template) = TEMPLATE.get() {
+        println!("{}", render_template(template, &serde_json::to_value(value)?));
+        return Ok(());
+    }
+
+    let value = project_fields(serde_json::to_value(value)?);
+    println!("{}", json_string(&value)?);
+    Ok(())
+}
+
+/// JSON envelope for list results, carrying what's needed to fetch the next
+/// page. Skip-based endpoints set `nextSkip`, cursor-based ones `nextCursor`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ListPage<'a> {
+    items: Value,
+    has_more: bool,
+    next_cursor: Option<&'a str>,
+    next_skip: Option<u32>,
+}
+
+/// Print list results wrapped in the JSON list envelope, applying --fields
+/// to each item. With --template or --format ndjson, prints one line per item
+/// instead.
+pub fn print_json_list<T: Serialize>(
+    items: &[T],
+    has_more: bool,
+    next_cursor: Option<&str>,
+    next_skip: Option<u32>,
+) -> anyhow::Result<()> {
+    if TEMPLATE.get().is_some() || JSON_LINES.load(Ordering::Relaxed) {
+        for item in items {
+            print_json_line(item)?;
+        }
+        return Ok(());
+    }
+
+    let page = ListPage {
+        items: project_fields(serde_json::to_value(items)?),
+        has_more,
+        next_cursor: next_cursor.filter(|_| has_more),
+        next_skip: next_skip.filter(|_| has_more),
+    };
+    println!("{}", json_string(&page)?);
+    Ok(())
+}
+
+/// Print how many items a `--count-only` list found: the bare number, or
+/// `{"count": N, "complete": bool}` as JSON. An incomplete count is only part
+/// of the total, and is also flagged with a warning.
+pub fn print_count(count: usize, complete: bool, json: bool) -> anyhow::Result<()> {
+    if json {
+        let value = serde_json::json!({ "count": count, "complete": complete });
+        println!("{}", json_string(&value)?);
+    } else {
+        if !complete {
+            eprintln!("{} count is incomplete", "warning:".yellow());
+        }
+        println!("{}", count);
+    }
+    Ok(())
+}
+
+/// Print one list item on its own line, applying --fields or --template.
+/// Used to stream `--format ndjson` results page by page.
+pub fn print_json_line<T: Serialize>(item: &T) -> anyhow::Result<()> {
+    let value = serde_json::to_value(item)?;
+    match TEMPLATE.get() {
+        Some(template) => println!("{}", render_template(template, &value)),
+        None => println!("{}", serde_json::to_string(&project_fields(value))?),
+    }
+    Ok(())
+}
+
+/// Quote a CSV field per RFC 4180 when it contains a delimiter, quote, or newline
+pub fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Print a header row followed by data rows as CSV
+pub fn print_csv<I>(headers: &[&str], rows: I)
+where
+    I: IntoIterator<Item = Vec<String>>,
+{
+    println!("{}", headers.join(","));
+    for row in rows {
+        let fields: Vec<String> = row.iter().map(|f| csv_field(f)).collect();
+        println!("{}", fields.join(","));
+    }
+}
+
+/// Print rows as a table that fits the terminal width, truncating long cells
+/// with an ellipsis. Falls back to plain ASCII when color is disabled.
+pub fn print_table<I>(headers: &[&str], rows: I)
+where
+    I: IntoIterator<Item = Vec<String>>,
+{
+    let plain = !colored::control::SHOULD_COLORIZE.should_colorize();
+
+    let mut table = Table::new();
+    if plain {
+        table.load_preset(ASCII_FULL).set_truncation_indicator("...");
+    } else {
+        table.load_preset(UTF8_FULL).set_truncation_indicator("…");
+    }
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(headers.to_vec());
+
+    for row in rows {
+        let mut row = Row::from(row);
+        row.max_height(1);
+        table.add_row(row);
+    }
+
+    println!("{table}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unterminated_placeholder_is_rejected() {
+        assert!(check_template("{id}\t{author.name}").is_ok());
+        assert!(check_template("{{literal}} \\{").is_ok());
+        assert!(check_template("{id} {name").is_err());
+        assert!(check_template("{{{id}").is_ok());
+        assert!(check_template("{{{id").is_err());
+    }
+}
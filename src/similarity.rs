@@ -0,0 +1,98 @@
+use std::collections::HashSet;
+
+/// How alike two titles are, from 0 to 1: the better of the share of words
+/// they have in common and one minus their edit distance over the longer
+/// title's length, both ignoring case and punctuation
+pub fn title_similarity(a: &str, b: &str) -> f64 {
+    let normalize = |title: &str| -> Vec<String> {
+        title
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect()
+    };
+    let (a, b) = (normalize(a), normalize(b));
+
+    let a_words: HashSet<&String> = a.iter().collect();
+    let b_words: HashSet<&String> = b.iter().collect();
+    let union = a_words.union(&b_words).count();
+    let overlap = if union == 0 {
+        0.0
+    } else {
+        a_words.intersection(&b_words).count() as f64 / union as f64
+    };
+
+    let a: Vec<char> = a.join(" ").chars().collect();
+    let b: Vec<char> = b.join(" ").chars().collect();
+    let longest = a.len().max(b.len());
+    let edit = if longest == 0 {
+        0.0
+    } else {
+        1.0 - levenshtein(&a, &b) as f64 / longest as f64
+    };
+
+    overlap.max(edit)
+}
+
+/// Number of single-character insertions, deletions and substitutions
+/// needed to turn `a` into `b`
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.iter().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+
+    #[test]
+    fn levenshtein_counts_edits() {
+        assert_eq!(levenshtein(&chars("kitten"), &chars("sitting")), 3);
+        assert_eq!(levenshtein(&chars("same"), &chars("same")), 0);
+        assert_eq!(levenshtein(&chars(""), &chars("abc")), 3);
+        assert_eq!(levenshtein(&chars("abc"), &chars("")), 3);
+        assert_eq!(levenshtein(&chars("é"), &chars("e")), 1);
+    }
+
+    #[test]
+    fn identical_titles_ignoring_case_and_punctuation() {
+        assert_eq!(title_similarity("Dark mode!", "dark   MODE"), 1.0);
+    }
+
+    #[test]
+    fn unrelated_titles_score_low() {
+        assert!(title_similarity("Dark mode", "Export to CSV") < 0.4);
+    }
+
+    #[test]
+    fn shared_words_in_any_order() {
+        let similarity = title_similarity("Export posts to CSV", "CSV export");
+        assert_eq!(similarity, 0.5);
+    }
+
+    #[test]
+    fn small_typos_score_by_edit_distance() {
+        let similarity = title_similarity("Dark mode", "Drak mode");
+        assert!(similarity > 0.7 && similarity < 1.0, "{}", similarity);
+    }
+
+    #[test]
+    fn empty_or_punctuation_only_titles_match_nothing() {
+        assert_eq!(title_similarity("", ""), 0.0);
+        assert_eq!(title_similarity("", "Dark mode"), 0.0);
+        assert_eq!(title_similarity("!!!", "???"), 0.0);
+        assert_eq!(title_similarity("?!", "Dark mode"), 0.0);
+    }
+}